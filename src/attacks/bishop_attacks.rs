@@ -4,9 +4,9 @@ use std::arch::x86_64::_pext_u64;
 use crate::{Bitboard, Square};
 
 #[cfg(not(feature = "pext"))]
-const BISHOP_ATTACKS: [Bitboard; 512 * 64] = unsafe { std::mem::transmute(*include_bytes!("attack_binpacks/bishop_attacks.bin")) };
+static BISHOP_ATTACKS: [Bitboard; 512 * 64] = unsafe { std::mem::transmute(*include_bytes!("attack_binpacks/bishop_attacks.bin")) };
 #[cfg(feature = "pext")]
-const BISHOP_ATTACKS: [Bitboard; 512 * 64] = unsafe { std::mem::transmute(*include_bytes!("attack_binpacks/bishop_attacks_pext.bin")) };
+static BISHOP_ATTACKS: [Bitboard; 512 * 64] = unsafe { std::mem::transmute(*include_bytes!("attack_binpacks/bishop_attacks_pext.bin")) };
 
 pub struct BishopAttacks;
 impl BishopAttacks {
@@ -1,8 +1,8 @@
-use crate::{Bitboard, ChessBoard, Piece, Square};
+use crate::{Bitboard, ChessBoard, Piece, Side, Square};
 
 use super::{
     bishop_attacks::BishopAttacks, king_attacks::KingAttacks, knight_attacks::KnightAttacks,
-    pawn_attacks::PawnsAttacks, rook_attacks::RookAttacks,
+    pawn_attacks::PawnsAttacks, rays::Rays, rook_attacks::RookAttacks,
 };
 
 pub struct Attacks;
@@ -31,6 +31,54 @@ impl Attacks {
     pub fn get_rook_attacks_for_square(square: Square, occupancy: Bitboard) -> Bitboard {
         RookAttacks::get_rook_attacks(square, occupancy)
     }
+
+    //Squares strictly between a and b, empty when they are not on a common line
+    #[inline]
+    pub fn between(a: Square, b: Square) -> Bitboard {
+        Rays::get_ray(a, b).exclude(b)
+    }
+
+    //Full rank, file or diagonal through both squares, empty when they are not on a common line
+    #[inline]
+    pub fn line_through(a: Square, b: Square) -> Bitboard {
+        Rays::get_line(a, b)
+    }
+
+    //Squares a rook would additionally attack once the first blockers it hits are removed
+    #[inline]
+    pub fn xray_rook(square: Square, occupancy: Bitboard, blockers: Bitboard) -> Bitboard {
+        let attacks = Self::get_rook_attacks_for_square(square, occupancy);
+        let blockers = blockers & attacks;
+        attacks ^ Self::get_rook_attacks_for_square(square, occupancy ^ blockers)
+    }
+
+    #[inline]
+    pub fn xray_bishop(square: Square, occupancy: Bitboard, blockers: Bitboard) -> Bitboard {
+        let attacks = Self::get_bishop_attacks_for_square(square, occupancy);
+        let blockers = blockers & attacks;
+        attacks ^ Self::get_bishop_attacks_for_square(square, occupancy ^ blockers)
+    }
+
+    pub fn attacks_of(piece: Piece, side: Side, square: Square, occupancy: Bitboard) -> Bitboard {
+        match piece {
+            Piece::PAWN => {
+                if side == Side::WHITE {
+                    Self::get_pawn_attacks_for_square::<true>(square)
+                } else {
+                    Self::get_pawn_attacks_for_square::<false>(square)
+                }
+            }
+            Piece::KNIGHT => Self::get_knight_attacks_for_square(square),
+            Piece::BISHOP => Self::get_bishop_attacks_for_square(square, occupancy),
+            Piece::ROOK => Self::get_rook_attacks_for_square(square, occupancy),
+            Piece::QUEEN => {
+                Self::get_bishop_attacks_for_square(square, occupancy)
+                    | Self::get_rook_attacks_for_square(square, occupancy)
+            }
+            Piece::KING => Self::get_king_attacks_for_square(square),
+            _ => Bitboard::EMPTY,
+        }
+    }
 }
 
 impl ChessBoard {
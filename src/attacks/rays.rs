@@ -6,9 +6,14 @@ impl Rays {
     pub fn get_ray(from: Square, to: Square) -> Bitboard {
         RAYS[from.get_raw() as usize][to.get_raw() as usize]
     }
+
+    #[inline]
+    pub fn get_line(a: Square, b: Square) -> Bitboard {
+        LINES[a.get_raw() as usize][b.get_raw() as usize]
+    }
 }
 
-const RAYS: [[Bitboard; 64]; 64] = {
+static RAYS: [[Bitboard; 64]; 64] = {
     let mut result = [[Bitboard::EMPTY; 64]; 64];
     let mut from_square_index = 0;
     while from_square_index < 64 {
@@ -26,6 +31,51 @@ const RAYS: [[Bitboard; 64]; 64] = {
     result
 };
 
+static LINES: [[Bitboard; 64]; 64] = {
+    let mut result = [[Bitboard::EMPTY; 64]; 64];
+    let mut a_index = 0;
+    while a_index < 64 {
+        let mut b_index = 0;
+        while b_index < 64 {
+            let a = Square::from_raw(a_index);
+            let b = Square::from_raw(b_index);
+            result[a_index as usize][b_index as usize] = generate_line(a, b);
+            b_index += 1;
+        }
+        a_index += 1;
+    }
+
+    result
+};
+
+//Whole rank, file or diagonal going through both squares from edge to edge
+const fn generate_line(a: Square, b: Square) -> Bitboard {
+    if generate_ray(a, b).is_empty() {
+        return Bitboard::EMPTY;
+    }
+
+    let rank_increment = (b.get_rank() as i32 - a.get_rank() as i32).signum();
+    let file_increment = (b.get_file() as i32 - a.get_file() as i32).signum();
+
+    let mut result = a.get_bit().get_raw();
+    let mut side = 0;
+    while side < 2 {
+        let sign = if side == 0 { 1 } else { -1 };
+        let mut rank = a.get_rank() as i32 + rank_increment * sign;
+        let mut file = a.get_file() as i32 + file_increment * sign;
+        while rank >= 0 && rank <= 7 && file >= 0 && file <= 7 {
+            result |= Square::from_coords(rank as u8, file as u8)
+                .get_bit()
+                .get_raw();
+            rank += rank_increment * sign;
+            file += file_increment * sign;
+        }
+        side += 1;
+    }
+
+    Bitboard::from_raw(result)
+}
+
 const fn generate_ray(from: Square, to: Square) -> Bitboard {
     let rank_increment = (to.get_rank() as i32 - from.get_rank() as i32).signum();
     let file_increment = (to.get_file() as i32 - from.get_file() as i32).signum();
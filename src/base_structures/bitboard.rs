@@ -8,7 +8,7 @@ use std::{
     },
 };
 
-use super::square::Square;
+use super::{direction::Direction, square::Square};
 
 #[derive(Copy, Clone, Default, PartialEq, PartialOrd, Hash)]
 pub struct Bitboard(u64);
@@ -146,6 +146,11 @@ impl Bitboard {
         Self(self.get_raw().swap_bytes())
     }
 
+    #[inline]
+    pub const fn mirror(&self) -> Self {
+        Self(self.get_raw().reverse_bits().swap_bytes())
+    }
+
     #[inline]
     pub const fn include(&self, square: Square) -> Self {
         self.or(square.get_bit())
@@ -166,6 +171,46 @@ impl Bitboard {
         Self(self.get_raw() >> rhs)
     }
 
+    //Moves every bit one step in the direction, bits leaving the board are dropped instead
+    //of wrapping to the other side
+    #[inline]
+    pub const fn shift(self, direction: Direction) -> Self {
+        let shifted = if direction.get_raw() > 0 {
+            self.shift_left(direction.get_raw() as u32)
+        } else {
+            self.shift_right(direction.get_raw().unsigned_abs() as u32)
+        };
+
+        match direction.file_offset() {
+            1 => shifted.and(Self::FILE_A.inverse()),
+            -1 => shifted.and(Self::FILE_H.inverse()),
+            _ => shifted,
+        }
+    }
+
+    #[inline]
+    pub const fn shift_times(self, direction: Direction, times: u32) -> Self {
+        let mut result = self;
+        let mut step = 0;
+        while step < times {
+            result = result.shift(direction);
+            step += 1;
+        }
+        result
+    }
+
+    //Extends every bit in the direction until it leaves the board or reaches a square outside
+    //of empty, the stopping squares are not included
+    pub const fn fill(self, direction: Direction, empty: Bitboard) -> Self {
+        let mut result = self;
+        let mut frontier = self;
+        while frontier.is_not_empty() {
+            frontier = frontier.shift(direction).and(empty).and(result.inverse());
+            result = result.or(frontier);
+        }
+        result
+    }
+
     #[inline]
     pub const fn wrapping_mul(self, rhs: Bitboard) -> Self {
         Self(self.get_raw().wrapping_mul(rhs.get_raw()))
@@ -421,7 +466,6 @@ impl IntoIterator for Bitboard {
     type Item = Square;
     type IntoIter = Biterator;
 
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         Biterator { board: self }
     }
@@ -430,7 +474,6 @@ impl IntoIterator for Bitboard {
 impl Iterator for Biterator {
     type Item = Square;
 
-    #[must_use]
     fn next(&mut self) -> Option<Self::Item> {
         if self.board.is_empty() {
             None
@@ -1,6 +1,8 @@
 use std::fmt::{Display, Formatter, Result};
 
-#[derive(Copy, Clone, Default, PartialEq, Hash)]
+use super::Square;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Hash)]
 pub struct CastleRights(u8);
 impl CastleRights {
     pub const WHITE_QUEEN: Self = Self(0b1000);
@@ -22,6 +24,12 @@ impl CastleRights {
 
     pub const ROOK_POSITIONS: [u8; 2] = [0, 7];
 
+    //Indexed by right index (BLACK_KING, BLACK_QUEEN, WHITE_KING, WHITE_QUEEN)
+    pub const DEFAULT_ROOK_SQUARES: [Square; 4] = [Square::H8, Square::A8, Square::H1, Square::A1];
+
+    pub const WHITE_RIGHTS: Self = Self(0b1100);
+    pub const BLACK_RIGHTS: Self = Self(0b0011);
+
     #[inline]
     pub fn from_raw(raw: u8) -> Self {
         Self(raw)
@@ -0,0 +1,89 @@
+use super::Square;
+
+//Compass direction from white's point of view, stored as the square index offset of one step
+#[derive(Copy, Clone, Debug, PartialEq, Hash)]
+pub struct Direction(i8);
+impl Direction {
+    pub const NORTH: Self = Self(8);
+    pub const SOUTH: Self = Self(-8);
+    pub const EAST: Self = Self(1);
+    pub const WEST: Self = Self(-1);
+    pub const NORTH_EAST: Self = Self(9);
+    pub const NORTH_WEST: Self = Self(7);
+    pub const SOUTH_EAST: Self = Self(-7);
+    pub const SOUTH_WEST: Self = Self(-9);
+
+    pub const ORTHOGONAL: [Self; 4] = [Self::NORTH, Self::SOUTH, Self::EAST, Self::WEST];
+    pub const DIAGONAL: [Self; 4] = [
+        Self::NORTH_EAST,
+        Self::NORTH_WEST,
+        Self::SOUTH_EAST,
+        Self::SOUTH_WEST,
+    ];
+    pub const ALL: [Self; 8] = [
+        Self::NORTH,
+        Self::SOUTH,
+        Self::EAST,
+        Self::WEST,
+        Self::NORTH_EAST,
+        Self::NORTH_WEST,
+        Self::SOUTH_EAST,
+        Self::SOUTH_WEST,
+    ];
+
+    #[inline]
+    pub const fn get_raw(&self) -> i8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn opposite(&self) -> Self {
+        Self(-self.0)
+    }
+
+    #[inline]
+    pub const fn rank_offset(&self) -> i8 {
+        match self.0 {
+            7..=9 => 1,
+            -9..=-7 => -1,
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    pub const fn file_offset(&self) -> i8 {
+        self.0 - self.rank_offset() * 8
+    }
+
+    #[inline]
+    pub const fn is_diagonal(&self) -> bool {
+        self.rank_offset() != 0 && self.file_offset() != 0
+    }
+
+    //Direction of a queen move from one square to the other, None when the squares are
+    //not on a common rank, file or diagonal
+    pub const fn between(from: Square, to: Square) -> Option<Self> {
+        let rank_delta = to.get_rank() as i8 - from.get_rank() as i8;
+        let file_delta = to.get_file() as i8 - from.get_file() as i8;
+        if rank_delta == 0 && file_delta == 0 {
+            return None;
+        }
+
+        if rank_delta != 0 && file_delta != 0 && rank_delta.abs() != file_delta.abs() {
+            return None;
+        }
+
+        Some(Self(rank_delta.signum() * 8 + file_delta.signum()))
+    }
+
+    //Square one step away, None when the step would leave the board
+    pub const fn step(&self, square: Square) -> Option<Square> {
+        let rank = square.get_rank() as i8 + self.rank_offset();
+        let file = square.get_file() as i8 + self.file_offset();
+        if rank < 0 || rank > 7 || file < 0 || file > 7 {
+            return None;
+        }
+
+        Some(Square::from_coords(rank as u8, file as u8))
+    }
+}
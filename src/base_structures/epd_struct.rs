@@ -0,0 +1,289 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{ChessBoard, Move, MoveParseError};
+
+use super::{FenError, FEN};
+
+//Position part of an EPD line with its opcodes kept in their original order
+#[derive(Clone, PartialEq, Default)]
+pub struct EPD {
+    fen: FEN,
+    opcodes: Vec<(String, Vec<String>)>,
+}
+
+impl EPD {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(epd_string: &str) -> Self {
+        match Self::parse(epd_string) {
+            Ok(epd) => epd,
+            Err(error) => panic!("Invalid EPD \"{epd_string}\": {error}"),
+        }
+    }
+
+    pub fn parse(epd_string: &str) -> std::result::Result<Self, EpdError> {
+        let mut rest = epd_string.trim();
+        let mut fields = Vec::new();
+        while fields.len() < 4 {
+            let field_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            if field_end == 0 {
+                return Err(EpdError::InvalidFen(FenError::MissingFields(fields.len())));
+            }
+            fields.push(&rest[..field_end]);
+            rest = rest[field_end..].trim_start();
+        }
+
+        let opcodes = parse_opcodes(rest)?;
+        let operand = |name: &str| {
+            opcodes
+                .iter()
+                .find(|(opcode, _)| opcode == name)
+                .and_then(|(_, operands)| operands.first())
+        };
+
+        let half_moves = operand("hmvc").map_or("0", |value| value.as_str());
+        let full_moves = operand("fmvn").map_or("1", |value| value.as_str());
+        let fen = FEN::parse(&format!("{} {half_moves} {full_moves}", fields.join(" ")))
+            .map_err(EpdError::InvalidFen)?;
+
+        Ok(Self { fen, opcodes })
+    }
+
+    pub fn from_fen(fen: &FEN) -> Self {
+        Self {
+            fen: fen.clone(),
+            opcodes: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn fen(&self) -> &FEN {
+        &self.fen
+    }
+
+    #[inline]
+    pub fn board(&self) -> std::result::Result<ChessBoard, FenError> {
+        ChessBoard::try_from_fen(&self.fen)
+    }
+
+    #[inline]
+    pub fn opcodes(&self) -> &[(String, Vec<String>)] {
+        &self.opcodes
+    }
+
+    pub fn get_opcode(&self, name: &str) -> Option<&[String]> {
+        self.opcodes
+            .iter()
+            .find(|(opcode, _)| opcode == name)
+            .map(|(_, operands)| operands.as_slice())
+    }
+
+    //Replaces the operands in place if the opcode exists, otherwise appends it
+    pub fn set_opcode(&mut self, name: &str, operands: Vec<String>) {
+        match self.opcodes.iter_mut().find(|(opcode, _)| opcode == name) {
+            Some((_, current)) => *current = operands,
+            None => self.opcodes.push((name.to_string(), operands)),
+        }
+    }
+
+    pub fn remove_opcode(&mut self, name: &str) {
+        self.opcodes.retain(|(opcode, _)| opcode != name)
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.get_opcode("id")
+            .and_then(|operands| operands.first())
+            .map(|id| id.as_str())
+    }
+
+    pub fn best_moves(&self) -> std::result::Result<Vec<Move>, EpdError> {
+        self.resolve_moves("bm", false)
+    }
+
+    pub fn avoid_moves(&self) -> std::result::Result<Vec<Move>, EpdError> {
+        self.resolve_moves("am", false)
+    }
+
+    //Moves of the pv are played one after another, each resolved in the position it is played from
+    pub fn principal_variation(&self) -> std::result::Result<Vec<Move>, EpdError> {
+        self.resolve_moves("pv", true)
+    }
+
+    pub fn centipawn_evaluation(&self) -> Option<i32> {
+        self.get_opcode("ce")
+            .and_then(|operands| operands.first())
+            .and_then(|value| value.parse().ok())
+    }
+
+    //Perft counts stored as D1 20; D2 400; ... sorted by depth
+    pub fn perft_counts(&self) -> std::result::Result<Vec<(u8, u64)>, EpdError> {
+        let mut result = Vec::new();
+        for (opcode, operands) in &self.opcodes {
+            let Some(depth) = opcode
+                .strip_prefix('D')
+                .and_then(|depth| depth.parse::<u8>().ok())
+            else {
+                continue;
+            };
+
+            let count = operands
+                .first()
+                .and_then(|count| count.parse::<u64>().ok())
+                .ok_or_else(|| EpdError::InvalidOperand(opcode.clone()))?;
+            result.push((depth, count));
+        }
+
+        result.sort_by_key(|&(depth, _)| depth);
+        Ok(result)
+    }
+
+    fn resolve_moves(
+        &self,
+        name: &str,
+        sequential: bool,
+    ) -> std::result::Result<Vec<Move>, EpdError> {
+        let Some(operands) = self.get_opcode(name) else {
+            return Ok(Vec::new());
+        };
+
+        let mut board = self.board().map_err(EpdError::InvalidFen)?;
+        let mut result = Vec::new();
+        for operand in operands {
+            let mv = board
+                .parse_san(operand)
+                .or_else(|error| board.parse_uci_move(operand).map_err(|_| error))
+                .map_err(|error| EpdError::InvalidMove {
+                    opcode: name.to_string(),
+                    error,
+                })?;
+
+            if sequential {
+                board.play(mv);
+            }
+            result.push(mv);
+        }
+        Ok(result)
+    }
+}
+
+fn parse_opcodes(mut rest: &str) -> std::result::Result<Vec<(String, Vec<String>)>, EpdError> {
+    let mut result = Vec::new();
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ';');
+        if rest.is_empty() {
+            return Ok(result);
+        }
+
+        let opcode_end = rest
+            .find(|c: char| c.is_whitespace() || c == ';')
+            .unwrap_or(rest.len());
+        let opcode = &rest[..opcode_end];
+        if !opcode.starts_with(|c: char| c.is_ascii_alphabetic())
+            || !opcode
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(EpdError::InvalidOpcode(opcode.to_string()));
+        }
+        rest = &rest[opcode_end..];
+
+        let mut operands = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            if let Some(remaining) = rest.strip_prefix(';') {
+                rest = remaining;
+                break;
+            }
+
+            if let Some(string_operand) = rest.strip_prefix('"') {
+                let string_end = string_operand
+                    .find('"')
+                    .ok_or_else(|| EpdError::UnterminatedString(opcode.to_string()))?;
+                operands.push(string_operand[..string_end].to_string());
+                rest = &string_operand[string_end + 1..];
+            } else {
+                let operand_end = rest
+                    .find(|c: char| c.is_whitespace() || c == ';')
+                    .unwrap_or(rest.len());
+                operands.push(rest[..operand_end].to_string());
+                rest = &rest[operand_end..];
+            }
+        }
+
+        result.push((opcode.to_string(), operands));
+    }
+}
+
+//id and comments c0-c9 always take a string operand, everything else is only quoted when required
+fn is_string_opcode(opcode: &str) -> bool {
+    opcode == "id"
+        || (opcode.len() == 2 && opcode.starts_with('c') && opcode.as_bytes()[1].is_ascii_digit())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EpdError {
+    InvalidFen(FenError),
+    InvalidOpcode(String),
+    UnterminatedString(String),
+    InvalidOperand(String),
+    InvalidMove {
+        opcode: String,
+        error: MoveParseError,
+    },
+}
+
+impl Display for EpdError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            EpdError::InvalidFen(error) => write!(formatter, "invalid position: {error}"),
+            EpdError::InvalidOpcode(opcode) => write!(formatter, "invalid opcode '{opcode}'"),
+            EpdError::UnterminatedString(opcode) => {
+                write!(
+                    formatter,
+                    "unterminated string operand for opcode '{opcode}'"
+                )
+            }
+            EpdError::InvalidOperand(opcode) => {
+                write!(formatter, "invalid operand for opcode '{opcode}'")
+            }
+            EpdError::InvalidMove { opcode, error } => {
+                write!(formatter, "invalid move in opcode '{opcode}': {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EpdError {}
+
+impl Display for EPD {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(
+            formatter,
+            "{} {} {} {}",
+            self.fen.board.join("/"),
+            self.fen.side_to_move,
+            self.fen.castle_rights,
+            self.fen.en_passant_square
+        )?;
+
+        for (opcode, operands) in &self.opcodes {
+            write!(formatter, " {opcode}")?;
+            for operand in operands {
+                let needs_quotes = is_string_opcode(opcode)
+                    || operand.is_empty()
+                    || operand.contains(|c: char| c.is_whitespace() || c == ';');
+                if needs_quotes {
+                    write!(formatter, " \"{operand}\"")?;
+                } else {
+                    write!(formatter, " {operand}")?;
+                }
+            }
+            write!(formatter, ";")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter, Result};
 
-use super::Square;
+use super::{CastleRights, Side, Square};
 
 #[derive(Clone, PartialEq, Default)]
 pub struct FEN {
@@ -25,80 +25,186 @@ impl FEN {
         Self::from_str(fen_string.as_str())
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(fen_string: &str) -> Self {
+        match Self::parse(fen_string) {
+            Ok(fen) => fen,
+            Err(error) => panic!("Invalid FEN \"{fen_string}\": {error}"),
+        }
+    }
+
+    pub fn parse(fen_string: &str) -> std::result::Result<Self, FenError> {
         let fen_parts: Vec<&str> = fen_string.split_whitespace().collect();
+        if fen_parts.len() < 4 {
+            return Err(FenError::MissingFields(fen_parts.len()));
+        }
+        if fen_parts.len() > 6 {
+            return Err(FenError::TooManyFields(fen_parts.len()));
+        }
+
         let mut result: Self = Self::default();
         let board_parts: Vec<&str> = fen_parts[0].split('/').collect();
+        if board_parts.len() != 8 {
+            return Err(FenError::InvalidRankCount(board_parts.len()));
+        }
+
         for (index, part) in board_parts.into_iter().enumerate() {
+            let mut length = 0;
+            for piece_char in part.chars() {
+                length += match piece_char {
+                    '1'..='8' => piece_char as usize - '0' as usize,
+                    'P' | 'N' | 'B' | 'R' | 'Q' | 'K' | 'p' | 'n' | 'b' | 'r' | 'q' | 'k' => 1,
+                    _ => return Err(FenError::InvalidPiece(piece_char)),
+                };
+            }
+
+            if length != 8 {
+                return Err(FenError::InvalidRankLength(8 - index as u8));
+            }
+
             result.board[index] = part.to_string()
         }
 
+        if fen_parts[1] != "w" && fen_parts[1] != "b" {
+            return Err(FenError::InvalidSideToMove(fen_parts[1].to_string()));
+        }
         result.side_to_move = fen_parts[1].to_string();
+
+        if !validate_castle_rights(fen_parts[2]) {
+            return Err(FenError::InvalidCastleRights(fen_parts[2].to_string()));
+        }
         result.castle_rights = fen_parts[2].to_string();
+
+        if fen_parts[3] != "-" {
+            match Square::try_from_string(fen_parts[3]) {
+                Some(square) if square.get_rank() == 2 || square.get_rank() == 5 => {}
+                _ => return Err(FenError::InvalidEnPassantSquare(fen_parts[3].to_string())),
+            }
+        }
         result.en_passant_square = fen_parts[3].to_string();
 
         result.half_move_counter = if fen_parts.len() > 4 {
+            if fen_parts[4].parse::<u8>().is_err() {
+                return Err(FenError::InvalidHalfMoveCounter(fen_parts[4].to_string()));
+            }
             fen_parts[4]
         } else {
             "0"
         }
         .to_string();
         result.full_move_counter = if fen_parts.len() > 5 {
+            if fen_parts[5].parse::<u16>().is_err() {
+                return Err(FenError::InvalidFullMoveCounter(fen_parts[5].to_string()));
+            }
             fen_parts[5]
         } else {
             "1"
         }
         .to_string();
 
-        result
+        Ok(result)
     }
 
     pub fn validate_fen(fen_string: &str) -> bool {
-        let fen_parts: Vec<&str> = fen_string.split_whitespace().collect();
-
-        if fen_parts.len() < 4 {
-            return false;
-        }
+        Self::parse(fen_string).is_ok()
+    }
+}
 
-        let board_parts: Vec<&str> = fen_parts[0].split('/').collect();
-        if board_parts.len() != 8 {
-            return false;
-        }
+//Accepts standard (KQkq), X-FEN and Shredder-FEN (rook files, e.g. HAha) castle rights
+fn validate_castle_rights(castle_rights: &str) -> bool {
+    if castle_rights == "-" {
+        return true;
+    }
 
-        if fen_parts[1] != "w" && fen_parts[1] != "b" {
-            return false;
+    let mut white_rights = 0;
+    let mut black_rights = 0;
+    for (index, right_char) in castle_rights.char_indices() {
+        match right_char {
+            'K' | 'Q' | 'A'..='H' => white_rights += 1,
+            'k' | 'q' | 'a'..='h' => black_rights += 1,
+            _ => return false,
         }
 
-        let castle_rights_contain = fen_parts[2].contains('-')
-            || fen_parts[2].contains('K')
-            || fen_parts[2].contains('Q')
-            || fen_parts[2].contains('k')
-            || fen_parts[2].contains('q');
-        if fen_parts[2].len() > 4 || !castle_rights_contain {
+        if castle_rights[..index].contains(right_char) {
             return false;
         }
+    }
 
-        let square_validate = if fen_parts[3] != "-" {
-            Square::from_string(fen_parts[3]).get_raw() < 63
-        } else {
-            true
-        };
-        if fen_parts[3].len() > 2 || !square_validate {
-            return false;
-        }
+    !castle_rights.is_empty() && white_rights <= 2 && black_rights <= 2
+}
 
-        if fen_parts.len() > 4 && fen_parts[4].parse::<u8>().is_err() {
-            return false;
-        }
+#[derive(Clone, Debug, PartialEq)]
+pub enum FenError {
+    MissingFields(usize),
+    TooManyFields(usize),
+    InvalidRankCount(usize),
+    InvalidPiece(char),
+    InvalidRankLength(u8),
+    InvalidSideToMove(String),
+    InvalidCastleRights(String),
+    InvalidEnPassantSquare(String),
+    InvalidHalfMoveCounter(String),
+    InvalidFullMoveCounter(String),
+    MissingKing(Side),
+    TooManyKings(Side),
+    SideNotToMoveInCheck,
+    ImpossibleCastleRights(CastleRights),
+    ImpossibleEnPassant(Square),
+}
 
-        if fen_parts.len() > 5 && fen_parts[5].parse::<u16>().is_err() {
-            return false;
+impl Display for FenError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            FenError::MissingFields(count) => {
+                write!(formatter, "expected at least 4 fields, found {count}")
+            }
+            FenError::TooManyFields(count) => {
+                write!(formatter, "expected at most 6 fields, found {count}")
+            }
+            FenError::InvalidRankCount(count) => {
+                write!(
+                    formatter,
+                    "expected 8 ranks in piece placement, found {count}"
+                )
+            }
+            FenError::InvalidPiece(piece_char) => {
+                write!(formatter, "invalid piece character '{piece_char}'")
+            }
+            FenError::InvalidRankLength(rank) => {
+                write!(formatter, "rank {rank} does not describe exactly 8 squares")
+            }
+            FenError::InvalidSideToMove(side) => write!(formatter, "invalid side to move '{side}'"),
+            FenError::InvalidCastleRights(rights) => {
+                write!(formatter, "invalid castle rights '{rights}'")
+            }
+            FenError::InvalidEnPassantSquare(square) => {
+                write!(formatter, "invalid en passant square '{square}'")
+            }
+            FenError::InvalidHalfMoveCounter(counter) => {
+                write!(formatter, "invalid half move counter '{counter}'")
+            }
+            FenError::InvalidFullMoveCounter(counter) => {
+                write!(formatter, "invalid full move counter '{counter}'")
+            }
+            FenError::MissingKing(side) => write!(formatter, "{side} has no king"),
+            FenError::TooManyKings(side) => write!(formatter, "{side} has more than one king"),
+            FenError::SideNotToMoveInCheck => write!(formatter, "side not to move is in check"),
+            FenError::ImpossibleCastleRights(right) => write!(
+                formatter,
+                "castle right '{right}' has no king or rook on its home square"
+            ),
+            FenError::ImpossibleEnPassant(square) => {
+                write!(
+                    formatter,
+                    "en passant square {square} is impossible in this position"
+                )
+            }
         }
-
-        true
     }
 }
 
+impl std::error::Error for FenError {}
+
 impl Display for FEN {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
         write!(
@@ -1,9 +1,12 @@
 mod bitboard;
 mod castle_rights;
+mod direction;
+mod epd_struct;
 mod fen_struct;
 mod r#move;
 mod move_flags;
 mod move_history;
+mod move_list;
 mod piece;
 mod side;
 mod square;
@@ -11,9 +14,12 @@ mod zobrist;
 
 pub use bitboard::Bitboard;
 pub use castle_rights::CastleRights;
-pub use fen_struct::FEN;
+pub use direction::Direction;
+pub use epd_struct::{EpdError, EPD};
+pub use fen_struct::{FenError, FEN};
 pub use move_flags::MoveFlag;
 pub use move_history::MoveHistory;
+pub use move_list::MoveList;
 pub use piece::Piece;
 pub use r#move::Move;
 pub use side::Side;
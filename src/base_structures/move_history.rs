@@ -1,39 +1,93 @@
 use super::ZobristKey;
 
-#[derive(Clone, Copy, Hash)]
-pub struct MoveHistory([ZobristKey; 100], usize);
+//Keys of every position before each move of the game. The list grows as needed,
+//and repetition scans are limited by the half move counter instead of clearing it
+#[derive(Clone, Default, Hash)]
+pub struct MoveHistory {
+    keys: Vec<ZobristKey>,
+    root: usize,
+}
+
 impl MoveHistory {
     pub fn new() -> Self {
-        Self([ZobristKey::default(); 100], 0)
+        Self {
+            keys: Vec::with_capacity(256),
+            root: 0,
+        }
     }
 
     #[inline]
     pub fn push(&mut self, key: ZobristKey) {
-        self.0[self.1] = key;
-        self.1 += 1;
+        self.keys.push(key);
+    }
+
+    //Null moves are stored as a ZobristKey::NULL marker that repetition scans never cross
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.push(ZobristKey::NULL);
+    }
+
+    #[inline]
+    pub fn pop(&mut self) {
+        self.keys.pop();
+        self.root = self.root.min(self.keys.len());
     }
 
     #[inline]
     pub fn reset(&mut self) {
-        self.1 = 0;
+        self.keys.clear();
+        self.root = 0;
     }
 
+    //Marks the current position as the search root for repetition_since_root queries
     #[inline]
-    pub fn get_key_repetitions(&self, key: ZobristKey) -> i32 {
-        let mut repetitions = 0;
-        for value in 0..self.1 {
-            if key != self.0[value] {
-                continue;
-            }
+    pub fn set_root(&mut self) {
+        self.root = self.keys.len();
+    }
 
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    //Counts earlier occurrences of the current position, only looking at positions with the
+    //same side to move that are still reachable within the half move window
+    pub fn get_key_repetitions(&self, key: ZobristKey, half_moves: u8) -> u32 {
+        let mut repetitions = 0;
+        self.scan_window(key, half_moves, |_| {
             repetitions += 1;
-        }
+            false
+        });
         repetitions
     }
-}
 
-impl Default for MoveHistory {
-    fn default() -> Self {
-        Self::new()
+    //True if the current position already occurred after the search root
+    pub fn is_repetition_since_root(&self, key: ZobristKey, half_moves: u8) -> bool {
+        let mut found = false;
+        let root = self.root;
+        self.scan_window(key, half_moves, |index| {
+            found = index >= root;
+            found
+        });
+        found
+    }
+
+    fn scan_window<F: FnMut(usize) -> bool>(&self, key: ZobristKey, half_moves: u8, mut method: F) {
+        let window = self.keys.len().min(half_moves as usize);
+        for distance in 1..=window {
+            let index = self.keys.len() - distance;
+            if self.keys[index] == ZobristKey::NULL {
+                return;
+            }
+
+            if distance % 2 == 0 && self.keys[index] == key && method(index) {
+                return;
+            }
+        }
     }
 }
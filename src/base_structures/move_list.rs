@@ -0,0 +1,157 @@
+use std::ops::{Index, IndexMut};
+
+use super::Move;
+
+//Stack allocated list big enough for every legal position, with a score per move
+//that travels with it when the list is reordered
+#[derive(Clone, Copy)]
+pub struct MoveList {
+    moves: [Move; MoveList::CAPACITY],
+    scores: [i32; MoveList::CAPACITY],
+    length: usize,
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoveList {
+    //Most legal moves known in any reachable chess position
+    pub const CAPACITY: usize = 218;
+
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            moves: [Move::NULL; Self::CAPACITY],
+            scores: [0; Self::CAPACITY],
+            length: 0,
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, mv: Move) {
+        self.moves[self.length] = mv;
+        self.scores[self.length] = 0;
+        self.length += 1;
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[inline]
+    pub fn contains(&self, mv: Move) -> bool {
+        self.as_slice().contains(&mv)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[Move] {
+        &self.moves[..self.length]
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Move> {
+        self.as_slice().iter()
+    }
+
+    //Removes the move in O(1) by moving the last one into its place
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> Move {
+        assert!(index < self.length, "index {index} out of bounds");
+        let mv = self.moves[index];
+        self.length -= 1;
+        self.moves[index] = self.moves[self.length];
+        self.scores[index] = self.scores[self.length];
+        mv
+    }
+
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.moves[..self.length].swap(a, b);
+        self.scores[..self.length].swap(a, b);
+    }
+
+    #[inline]
+    pub fn get_score(&self, index: usize) -> i32 {
+        self.scores[..self.length][index]
+    }
+
+    #[inline]
+    pub fn set_score(&mut self, index: usize, score: i32) {
+        self.scores[..self.length][index] = score
+    }
+
+    #[inline]
+    pub fn scores(&self) -> &[i32] {
+        &self.scores[..self.length]
+    }
+
+    #[inline]
+    pub fn scores_mut(&mut self) -> &mut [i32] {
+        &mut self.scores[..self.length]
+    }
+
+    //Selection step for staged move ordering, moves the best scored move from index
+    //onwards to index and returns it
+    pub fn pick_best(&mut self, index: usize) -> Move {
+        let mut best_index = index;
+        for current in index + 1..self.length {
+            if self.scores[current] > self.scores[best_index] {
+                best_index = current;
+            }
+        }
+
+        self.swap(index, best_index);
+        self.moves[index]
+    }
+
+    //Stable sort from the highest score to the lowest
+    pub fn sort_by_score(&mut self) {
+        for index in 1..self.length {
+            let mut current = index;
+            while current > 0 && self.scores[current - 1] < self.scores[current] {
+                self.swap(current - 1, current);
+                current -= 1;
+            }
+        }
+    }
+}
+
+impl Index<usize> for MoveList {
+    type Output = Move;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl IndexMut<usize> for MoveList {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.moves[..self.length][index]
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
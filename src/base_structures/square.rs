@@ -5,7 +5,7 @@ use std::{
 
 use super::bitboard::Bitboard;
 
-#[derive(Copy, Clone, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Hash)]
 pub struct Square(u8);
 
 impl Default for Square {
@@ -121,6 +121,11 @@ impl Square {
         Self::from_raw(self.get_raw() ^ 56)
     }
 
+    #[inline]
+    pub const fn mirror(&self) -> Self {
+        Self::from_raw(self.get_raw() ^ 7)
+    }
+
     #[inline]
     pub fn shift_left(&self, shift: u32) -> Self {
         (self.get_bit() << shift).ls1b_square()
@@ -133,10 +138,25 @@ impl Square {
 
     pub fn from_string(square: &str) -> Square {
         let signatures: Vec<char> = square.chars().collect();
-        let file = signatures[0] as u8 - 'a' as u8;
+        let file = signatures[0] as u8 - b'a';
         let rank = signatures[1].to_string().parse::<u8>().unwrap() - 1;
         Square::from_coords(rank, file)
     }
+
+    pub fn try_from_string(square: &str) -> Option<Square> {
+        let signatures = square.as_bytes();
+        if signatures.len() != 2
+            || !(b'a'..=b'h').contains(&signatures[0])
+            || !(b'1'..=b'8').contains(&signatures[1])
+        {
+            return None;
+        }
+
+        Some(Square::from_coords(
+            signatures[1] - b'1',
+            signatures[0] - b'a',
+        ))
+    }
 }
 impl From<Square> for u8 {
     fn from(square: Square) -> Self {
@@ -183,6 +203,6 @@ impl Display for Square {
 
         let file = self.get_raw() % 8;
         let rank = ((self.get_raw() as f32) / 8_f32).floor() as u8 + 1;
-        write!(formatter, "{}{}", ('a' as u8 + file) as char, rank)
+        write!(formatter, "{}{}", (b'a' + file) as char, rank)
     }
 }
@@ -19,6 +19,13 @@ impl ZobristKey {
         self.0 ^= SEEDS[(usize::from(piece) + usize::from(WHITE) * 6) * 64 + usize::from(square)];
     }
 
+    //Material keys reuse the piece seeds, indexed by how many of the piece the side has
+    //instead of by square
+    #[inline]
+    pub(crate) fn update_material_hash<const WHITE: bool>(&mut self, piece: Piece, count: u32) {
+        self.0 ^= SEEDS[(usize::from(piece) + usize::from(WHITE) * 6) * 64 + count as usize];
+    }
+
     #[inline]
     pub(crate) fn get_castle_rights_seed(mask: u8) -> u64 {
         SEEDS[769 + mask as usize]
@@ -0,0 +1,77 @@
+use crate::{CastleRights, ChessBoard, Piece, Side, Square};
+
+impl ChessBoard {
+    //Swaps colours and mirrors ranks, the returned position is the same position seen from
+    //the other side of the board
+    pub fn flipped(&self) -> Self {
+        let mut result = self.transformed(Square::flip, true);
+
+        let mut castle_rights = CastleRights::NULL;
+        for (right, flipped_right) in [
+            (CastleRights::WHITE_KING, CastleRights::BLACK_KING),
+            (CastleRights::WHITE_QUEEN, CastleRights::BLACK_QUEEN),
+            (CastleRights::BLACK_KING, CastleRights::WHITE_KING),
+            (CastleRights::BLACK_QUEEN, CastleRights::WHITE_QUEEN),
+        ] {
+            result.state.get_castle_rook_squares_mut()[flipped_right.get_index()] =
+                self.castle_rook_square(right).flip();
+            if self.castle_rights().has_right(right) {
+                castle_rights.set_right(flipped_right);
+            }
+        }
+        *result.state.get_castle_rights_mut() = castle_rights;
+
+        result
+    }
+
+    //Mirrors files, None when castling is still possible as castling is not symmetric
+    pub fn mirrored(&self) -> Option<Self> {
+        if self.castle_rights() != CastleRights::NULL {
+            return None;
+        }
+
+        Some(self.transformed(Square::mirror, false))
+    }
+
+    //Flipped and mirrored, None when castling is still possible
+    pub fn rotated(&self) -> Option<Self> {
+        self.mirrored().map(|board| board.flipped())
+    }
+
+    //Copies the position with every square mapped, castle rights are left empty
+    fn transformed(&self, square_map: fn(&Square) -> Square, swap_sides: bool) -> Self {
+        let mut result = ChessBoard::default();
+
+        for side in [Side::WHITE, Side::BLACK] {
+            let white = (side == Side::WHITE) != swap_sides;
+            for piece_index in Piece::PAWN.get_raw()..=Piece::KING.get_raw() {
+                let piece = Piece::from_raw(piece_index);
+                self.piece_mask_for_side(piece, side).map(|square| {
+                    if white {
+                        result.set_piece_on_square::<true>(square_map(&square), piece)
+                    } else {
+                        result.set_piece_on_square::<false>(square_map(&square), piece)
+                    }
+                });
+            }
+        }
+
+        *result.state.get_side_to_move_mut() = if swap_sides {
+            self.side_to_move().flipped()
+        } else {
+            self.side_to_move()
+        };
+        *result.state.get_en_passant_mut() = if self.en_passant_square() == Square::NULL {
+            Square::NULL
+        } else {
+            square_map(&self.en_passant_square())
+        };
+        *result.state.get_chess_960_mut() = self.is_chess_960();
+        *result.state.get_half_move_counter_mut() = self.half_move_counter();
+        *result.state.get_full_move_counter_mut() = self.full_move_counter();
+        *result.state.get_ply_mut() = self.ply();
+        *result.state.get_phase_mut() = self.get_phase();
+
+        result
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    attacks::{Attacks, Rays},
+    Bitboard, CastleRights, ChessBoard, Move, MoveFlag, Piece, Square,
+};
+
+//Per-position data for answering gives_check without making the move
+#[derive(Clone, Copy, PartialEq)]
+pub struct CheckInfo {
+    check_squares: [Bitboard; 6],
+    discovered_check_candidates: Bitboard,
+    enemy_king_square: Square,
+}
+
+impl CheckInfo {
+    //Squares a piece of the given type has to land on to check the enemy king
+    #[inline]
+    pub fn get_check_squares(&self, piece: Piece) -> Bitboard {
+        self.check_squares[usize::from(piece)]
+    }
+
+    //Pieces of the side to move that block one of its own sliders from the enemy king
+    #[inline]
+    pub fn get_discovered_check_candidates(&self) -> Bitboard {
+        self.discovered_check_candidates
+    }
+
+    #[inline]
+    pub fn get_enemy_king_square(&self) -> Square {
+        self.enemy_king_square
+    }
+}
+
+impl ChessBoard {
+    pub fn generate_check_info<const STM_WHITE: bool, const NSTM_WHITE: bool>(&self) -> CheckInfo {
+        let king_square = self.get_king_square::<NSTM_WHITE>();
+        let occupancy = self.get_occupancy();
+
+        let bishop_squares = Attacks::get_bishop_attacks_for_square(king_square, occupancy);
+        let rook_squares = Attacks::get_rook_attacks_for_square(king_square, occupancy);
+        let check_squares = [
+            Attacks::get_pawn_attacks_for_square::<NSTM_WHITE>(king_square),
+            Attacks::get_knight_attacks_for_square(king_square),
+            bishop_squares,
+            rook_squares,
+            bishop_squares | rook_squares,
+            Bitboard::EMPTY,
+        ];
+
+        let queens = self.get_piece_mask_for_side::<STM_WHITE>(Piece::QUEEN);
+        let snipers = (Attacks::get_bishop_attacks_for_square(king_square, Bitboard::EMPTY)
+            & (self.get_piece_mask_for_side::<STM_WHITE>(Piece::BISHOP) | queens))
+            | (Attacks::get_rook_attacks_for_square(king_square, Bitboard::EMPTY)
+                & (self.get_piece_mask_for_side::<STM_WHITE>(Piece::ROOK) | queens));
+
+        let mut discovered_check_candidates = Bitboard::EMPTY;
+        snipers.map(|sniper_square| {
+            let blockers = Attacks::between(king_square, sniper_square) & occupancy;
+            if blockers.only_one_bit() {
+                discovered_check_candidates |=
+                    blockers & self.get_occupancy_for_side::<STM_WHITE>();
+            }
+        });
+
+        CheckInfo {
+            check_squares,
+            discovered_check_candidates,
+            enemy_king_square: king_square,
+        }
+    }
+
+    pub fn move_gives_check<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mv: Move,
+        check_info: &CheckInfo,
+    ) -> bool {
+        let from_square = mv.get_from_square();
+        let to_square = mv.get_to_square();
+        let king_square = check_info.enemy_king_square;
+        let flag = mv.get_flag();
+
+        if flag == MoveFlag::KING_SIDE_CASTLE || flag == MoveFlag::QUEEN_SIDE_CASTLE {
+            return self.castle_gives_check::<STM_WHITE>(mv, king_square);
+        }
+
+        let piece = self.get_piece_on_square(from_square);
+        if check_info.discovered_check_candidates.get_bit(from_square)
+            && (Rays::get_ray(king_square, to_square) & from_square.get_bit()).is_empty()
+            && (Rays::get_ray(king_square, from_square) & to_square.get_bit()).is_empty()
+        {
+            return true;
+        }
+
+        if mv.is_promotion() {
+            let occupancy = self.get_occupancy() ^ from_square.get_bit();
+            let attacks = match mv.get_promotion_piece() {
+                Piece::KNIGHT => Attacks::get_knight_attacks_for_square(to_square),
+                Piece::BISHOP => Attacks::get_bishop_attacks_for_square(to_square, occupancy),
+                Piece::ROOK => Attacks::get_rook_attacks_for_square(to_square, occupancy),
+                _ => {
+                    Attacks::get_bishop_attacks_for_square(to_square, occupancy)
+                        | Attacks::get_rook_attacks_for_square(to_square, occupancy)
+                }
+            };
+            return attacks.get_bit(king_square);
+        }
+
+        if check_info.get_check_squares(piece).get_bit(to_square) {
+            return true;
+        }
+
+        //Removing both pawns can open a line that no single blocker test catches
+        if mv.is_en_passant() {
+            let captured_square = to_square ^ 8;
+            let occupancy =
+                (self.get_occupancy() ^ from_square.get_bit() ^ captured_square.get_bit())
+                    | to_square.get_bit();
+            return self.is_king_hit_by_sliders::<STM_WHITE>(
+                king_square,
+                occupancy,
+                Bitboard::EMPTY,
+            );
+        }
+
+        false
+    }
+
+    fn castle_gives_check<const STM_WHITE: bool>(&self, mv: Move, king_square: Square) -> bool {
+        let side_flip = if STM_WHITE { 0 } else { 56 };
+        let king_side = mv.get_flag() == MoveFlag::KING_SIDE_CASTLE;
+        let right = match (STM_WHITE, king_side) {
+            (true, true) => CastleRights::WHITE_KING,
+            (true, false) => CastleRights::WHITE_QUEEN,
+            (false, true) => CastleRights::BLACK_KING,
+            (false, false) => CastleRights::BLACK_QUEEN,
+        };
+        let rook_from = self.castle_rook_square(right);
+        let rook_to = Square::from_raw(side_flip + [3, 5][usize::from(king_side)]);
+
+        let occupancy =
+            (self.get_occupancy() ^ mv.get_from_square().get_bit() ^ rook_from.get_bit())
+                | mv.get_to_square().get_bit()
+                | rook_to.get_bit();
+        let moved_rook = rook_from.get_bit() ^ rook_to.get_bit();
+
+        self.is_king_hit_by_sliders::<STM_WHITE>(king_square, occupancy, moved_rook)
+    }
+
+    //Checks our sliders against the enemy king for a changed occupancy, rook_change toggles
+    //the bits of a rook that moved
+    fn is_king_hit_by_sliders<const STM_WHITE: bool>(
+        &self,
+        king_square: Square,
+        occupancy: Bitboard,
+        rook_change: Bitboard,
+    ) -> bool {
+        let queens = self.get_piece_mask_for_side::<STM_WHITE>(Piece::QUEEN);
+        let bishops = self.get_piece_mask_for_side::<STM_WHITE>(Piece::BISHOP) | queens;
+        let rooks = (self.get_piece_mask_for_side::<STM_WHITE>(Piece::ROOK) ^ rook_change) | queens;
+
+        (Attacks::get_bishop_attacks_for_square(king_square, occupancy) & bishops & occupancy)
+            .is_not_empty()
+            || (Attacks::get_rook_attacks_for_square(king_square, occupancy) & rooks & occupancy)
+                .is_not_empty()
+    }
+}
@@ -1,6 +1,6 @@
 use colored::Colorize;
 
-use crate::{CastleRights, ChessBoardPacked, Piece, PolicyPacked, Side, Square, FEN};
+use crate::{CastleRights, ChessBoardPacked, FenError, Piece, PolicyPacked, Side, Square, FEN};
 
 use super::{
     chess_board_pieces::ChessBoardPieces,
@@ -15,124 +15,130 @@ pub struct ChessBoard {
 
 impl ChessBoard {
     pub fn from_fen(fen: &FEN) -> Self {
+        Self::try_from_fen(fen).unwrap_or_default()
+    }
+
+    pub fn try_from_fen(fen: &FEN) -> Result<Self, FenError> {
         let mut board = Self {
             pieces: ChessBoardPieces::default(),
             state: ChessBoardState::default(),
         };
 
-        for (rank_index, rank) in fen.board.clone().into_iter().enumerate() {
-            let mut index = 0;
+        for (rank_index, rank) in fen.board.iter().enumerate() {
             let mut file = 0;
-            while file < 8 {
-                let square = Square::from_coords((7 - rank_index) as u8, file);
-                let piece_char = rank.as_bytes()[index] as char;
-                if piece_char.is_numeric() {
-                    file += piece_char.to_string().parse::<usize>().unwrap() as u8;
-                    index += 1;
+            for piece_char in rank.chars() {
+                if let '1'..='8' = piece_char {
+                    file += piece_char as u8 - b'0';
                     continue;
                 }
 
-                if piece_char == 'P' {
-                    board.set_piece_on_square::<true>(square, Piece::PAWN);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::PAWN.get_raw() as usize];
-                } else if piece_char == 'N' {
-                    board.set_piece_on_square::<true>(square, Piece::KNIGHT);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::KNIGHT.get_raw() as usize];
-                } else if piece_char == 'B' {
-                    board.set_piece_on_square::<true>(square, Piece::BISHOP);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::BISHOP.get_raw() as usize];
-                } else if piece_char == 'R' {
-                    board.set_piece_on_square::<true>(square, Piece::ROOK);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::ROOK.get_raw() as usize];
-                } else if piece_char == 'Q' {
-                    board.set_piece_on_square::<true>(square, Piece::QUEEN);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::QUEEN.get_raw() as usize];
-                } else if piece_char == 'K' {
-                    board.set_piece_on_square::<true>(square, Piece::KING);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::KING.get_raw() as usize];
-                } else if piece_char == 'p' {
-                    board.set_piece_on_square::<false>(square, Piece::PAWN);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::PAWN.get_raw() as usize];
-                } else if piece_char == 'n' {
-                    board.set_piece_on_square::<false>(square, Piece::KNIGHT);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::KNIGHT.get_raw() as usize];
-                } else if piece_char == 'b' {
-                    board.set_piece_on_square::<false>(square, Piece::BISHOP);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::BISHOP.get_raw() as usize];
-                } else if piece_char == 'r' {
-                    board.set_piece_on_square::<false>(square, Piece::ROOK);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::ROOK.get_raw() as usize];
-                } else if piece_char == 'q' {
-                    board.set_piece_on_square::<false>(square, Piece::QUEEN);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::QUEEN.get_raw() as usize];
-                } else if piece_char == 'k' {
-                    board.set_piece_on_square::<false>(square, Piece::KING);
-                    *board.state.get_phase_mut() += PHASE_VALUES[Piece::KING.get_raw() as usize];
+                if file >= 8 {
+                    return Err(FenError::InvalidRankLength(8 - rank_index as u8));
+                }
+
+                let piece = match piece_char.to_ascii_lowercase() {
+                    'p' => Piece::PAWN,
+                    'n' => Piece::KNIGHT,
+                    'b' => Piece::BISHOP,
+                    'r' => Piece::ROOK,
+                    'q' => Piece::QUEEN,
+                    'k' => Piece::KING,
+                    _ => return Err(FenError::InvalidPiece(piece_char)),
+                };
+
+                let square = Square::from_coords((7 - rank_index) as u8, file);
+                if piece_char.is_ascii_uppercase() {
+                    board.set_piece_on_square::<true>(square, piece);
+                } else {
+                    board.set_piece_on_square::<false>(square, piece);
                 }
+                *board.state.get_phase_mut() += PHASE_VALUES[piece.get_raw() as usize];
 
-                index += 1;
                 file += 1;
             }
-        }
 
-        if fen.side_to_move == "w" {
-            *board.state.get_side_to_move_mut() = Side::WHITE;
-        } else {
-            *board.state.get_side_to_move_mut() = Side::BLACK;
+            if file != 8 {
+                return Err(FenError::InvalidRankLength(8 - rank_index as u8));
+            }
         }
 
-        let king_square = if board.side_to_move() == Side::WHITE {
-            board.get_king_square::<false>()
-        } else {
-            board.get_king_square::<true>()
+        *board.state.get_side_to_move_mut() = match fen.side_to_move.as_str() {
+            "w" => Side::WHITE,
+            "b" => Side::BLACK,
+            side => return Err(FenError::InvalidSideToMove(side.to_string())),
         };
 
-        if if board.side_to_move() == Side::WHITE {
-            board.is_square_attacked::<false, true>(king_square)
-        } else {
-            board.is_square_attacked::<true, false>(king_square)
-        } {
-            println!("Illegal position!");
-            return Self {
-                pieces: ChessBoardPieces::default(),
-                state: ChessBoardState::default(),
-            };
+        for side in [Side::WHITE, Side::BLACK] {
+            match board.piece_mask_for_side(Piece::KING, side).pop_count() {
+                0 => return Err(FenError::MissingKing(side)),
+                1 => {}
+                _ => return Err(FenError::TooManyKings(side)),
+            }
         }
 
-        if fen.castle_rights.contains('K') {
-            board
-                .state
-                .get_castle_rights_mut()
-                .set_right(CastleRights::WHITE_KING);
+        let king_square = board.king_square(board.side_to_move().flipped());
+        if board.is_square_attacked_by(king_square, board.side_to_move()) {
+            return Err(FenError::SideNotToMoveInCheck);
         }
-        if fen.castle_rights.contains('Q') {
-            board
-                .state
-                .get_castle_rights_mut()
-                .set_right(CastleRights::WHITE_QUEEN);
-        }
-        if fen.castle_rights.contains('k') {
-            board
-                .state
-                .get_castle_rights_mut()
-                .set_right(CastleRights::BLACK_KING);
-        }
-        if fen.castle_rights.contains('q') {
-            board
-                .state
-                .get_castle_rights_mut()
-                .set_right(CastleRights::BLACK_QUEEN);
+
+        if fen.castle_rights != "-" {
+            for right_char in fen.castle_rights.chars() {
+                board.parse_castle_right(right_char, &fen.castle_rights)?;
+            }
         }
 
         *board.state.get_en_passant_mut() = Square::NULL;
         if fen.en_passant_square != "-" {
-            let new_square = Square::from_string(&fen.en_passant_square);
-            *board.state.get_en_passant_mut() = new_square;
+            let square = match Square::try_from_string(&fen.en_passant_square) {
+                Some(square) => square,
+                None => {
+                    return Err(FenError::InvalidEnPassantSquare(
+                        fen.en_passant_square.clone(),
+                    ))
+                }
+            };
+
+            let expected_rank = if board.side_to_move() == Side::WHITE {
+                5
+            } else {
+                2
+            };
+            let enemy_pawns =
+                board.piece_mask_for_side(Piece::PAWN, board.side_to_move().flipped());
+            if square.get_rank() != expected_rank
+                || board.get_occupancy().get_bit(square)
+                || board.get_occupancy().get_bit(square ^ 24)
+                || !enemy_pawns.get_bit(square ^ 8)
+            {
+                return Err(FenError::ImpossibleEnPassant(square));
+            }
+
+            *board.state.get_en_passant_mut() = square;
         }
 
-        *board.state.get_half_move_counter_mut() = fen.half_move_counter.parse().unwrap();
+        *board.state.get_half_move_counter_mut() = match fen.half_move_counter.parse() {
+            Ok(half_moves) => half_moves,
+            Err(_) => {
+                return Err(FenError::InvalidHalfMoveCounter(
+                    fen.half_move_counter.clone(),
+                ))
+            }
+        };
 
-        board
+        *board.state.get_full_move_counter_mut() = match fen.full_move_counter.parse() {
+            Ok(full_moves) => full_moves,
+            Err(_) => {
+                return Err(FenError::InvalidFullMoveCounter(
+                    fen.full_move_counter.clone(),
+                ))
+            }
+        };
+
+        *board.state.get_ply_mut() = (board.full_move_counter().saturating_sub(1))
+            .saturating_mul(2)
+            .saturating_add(board.side_to_move().get_raw() as u16);
+
+        Ok(board)
     }
 
     pub fn from_board_pack(pack: &ChessBoardPacked) -> Self {
@@ -204,6 +210,14 @@ impl ChessBoard {
     }
 
     pub fn get_fen(&self) -> FEN {
+        self.build_fen(false)
+    }
+
+    pub fn get_shredder_fen(&self) -> FEN {
+        self.build_fen(true)
+    }
+
+    fn build_fen(&self, shredder: bool) -> FEN {
         let mut fen = String::new();
 
         // Piece placement
@@ -246,23 +260,7 @@ impl ChessBoard {
 
         // Castling rights
         fen.push(' ');
-        let mut castling_rights = String::new();
-        if self.castle_rights().has_right(CastleRights::WHITE_KING) {
-            castling_rights.push('K');
-        }
-        if self.castle_rights().has_right(CastleRights::WHITE_QUEEN) {
-            castling_rights.push('Q');
-        }
-        if self.castle_rights().has_right(CastleRights::BLACK_KING) {
-            castling_rights.push('k');
-        }
-        if self.castle_rights().has_right(CastleRights::BLACK_QUEEN) {
-            castling_rights.push('q');
-        }
-        if castling_rights.is_empty() {
-            castling_rights.push('-');
-        }
-        fen.push_str(&castling_rights);
+        fen.push_str(&self.castle_rights_to_string(shredder));
 
         // En passant target square
         fen.push(' ');
@@ -276,7 +274,7 @@ impl ChessBoard {
         fen.push(' ');
         fen.push_str(&self.half_move_counter().to_string());
         fen.push(' ');
-        fen.push_str(&(1).to_string());
+        fen.push_str(&self.full_move_counter().to_string());
 
         FEN::from_string(fen)
     }
@@ -301,14 +299,7 @@ impl ChessBoard {
         info.push(en_passant.as_str());
         let half_moves = format!("Half Moves: {}", self.half_move_counter());
         info.push(half_moves.as_str());
-        let in_check = format!(
-            "In Check: {}",
-            if self.side_to_move() == Side::WHITE {
-                self.is_in_check::<true, false>()
-            } else {
-                self.is_in_check::<false, true>()
-            }
-        );
+        let in_check = format!("In Check: {}", self.in_check());
         info.push(in_check.as_str());
         let insufficient_material =
             format!("Insufficient material: {}", self.is_insufficient_material());
@@ -346,4 +337,128 @@ impl ChessBoard {
         result += " -----------------\n".to_string().as_str();
         println!("{}", result);
     }
+
+    //Parses one character of standard, X-FEN or Shredder-FEN castle rights
+    fn parse_castle_right(
+        &mut self,
+        right_char: char,
+        castle_rights: &str,
+    ) -> Result<(), FenError> {
+        let side = if right_char.is_ascii_uppercase() {
+            Side::WHITE
+        } else {
+            Side::BLACK
+        };
+        let back_rank = if side == Side::WHITE { 0 } else { 7 };
+        let king_square = self.king_square(side);
+        let rooks = self.piece_mask_for_side(Piece::ROOK, side);
+
+        let (king_side, rook_square) = match right_char.to_ascii_lowercase() {
+            'k' => (
+                true,
+                (king_square.get_file() + 1..8)
+                    .rev()
+                    .map(|file| Square::from_coords(back_rank, file))
+                    .find(|&square| rooks.get_bit(square)),
+            ),
+            'q' => (
+                false,
+                (0..king_square.get_file())
+                    .map(|file| Square::from_coords(back_rank, file))
+                    .find(|&square| rooks.get_bit(square)),
+            ),
+            file_char @ 'a'..='h' => {
+                let file = file_char as u8 - b'a';
+                (
+                    file > king_square.get_file(),
+                    Some(Square::from_coords(back_rank, file)),
+                )
+            }
+            _ => return Err(FenError::InvalidCastleRights(castle_rights.to_string())),
+        };
+
+        let right = match (side == Side::WHITE, king_side) {
+            (true, true) => CastleRights::WHITE_KING,
+            (true, false) => CastleRights::WHITE_QUEEN,
+            (false, true) => CastleRights::BLACK_KING,
+            (false, false) => CastleRights::BLACK_QUEEN,
+        };
+
+        if self.castle_rights().has_right(right) {
+            return Err(FenError::InvalidCastleRights(castle_rights.to_string()));
+        }
+
+        let rook_square = match rook_square {
+            Some(square)
+                if king_square.get_rank() == back_rank
+                    && square.get_file() != king_square.get_file()
+                    && rooks.get_bit(square) =>
+            {
+                square
+            }
+            _ => return Err(FenError::ImpossibleCastleRights(right)),
+        };
+
+        let file_notation = !matches!(right_char.to_ascii_lowercase(), 'k' | 'q');
+        let standard_rook_file = if king_side { 7 } else { 0 };
+        if file_notation
+            || king_square.get_file() != 4
+            || rook_square.get_file() != standard_rook_file
+        {
+            *self.state.get_chess_960_mut() = true;
+        }
+
+        self.state.get_castle_rights_mut().set_right(right);
+        self.state.get_castle_rook_squares_mut()[right.get_index()] = rook_square;
+        Ok(())
+    }
+
+    //X-FEN uses KQkq unless another rook stands between the castling rook and the corner,
+    //Shredder-FEN always uses rook files
+    fn castle_rights_to_string(&self, shredder: bool) -> String {
+        let mut result = String::new();
+        for (right, standard_char) in [
+            (CastleRights::WHITE_KING, 'K'),
+            (CastleRights::WHITE_QUEEN, 'Q'),
+            (CastleRights::BLACK_KING, 'k'),
+            (CastleRights::BLACK_QUEEN, 'q'),
+        ] {
+            if !self.castle_rights().has_right(right) {
+                continue;
+            }
+
+            let rook_square = self.castle_rook_square(right);
+            let side = if standard_char.is_ascii_uppercase() {
+                Side::WHITE
+            } else {
+                Side::BLACK
+            };
+            let rooks = self.piece_mask_for_side(Piece::ROOK, side);
+            let king_side = right == CastleRights::WHITE_KING || right == CastleRights::BLACK_KING;
+            let mut outer_files = if king_side {
+                rook_square.get_file() + 1..8
+            } else {
+                0..rook_square.get_file()
+            };
+            let outermost = !outer_files
+                .any(|file| rooks.get_bit(Square::from_coords(rook_square.get_rank(), file)));
+
+            if !shredder && outermost {
+                result.push(standard_char);
+            } else {
+                let file_char = (b'a' + rook_square.get_file()) as char;
+                result.push(if side == Side::WHITE {
+                    file_char.to_ascii_uppercase()
+                } else {
+                    file_char
+                });
+            }
+        }
+
+        if result.is_empty() {
+            result.push('-');
+        }
+
+        result
+    }
 }
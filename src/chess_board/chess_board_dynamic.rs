@@ -0,0 +1,245 @@
+use crate::{Bitboard, ChessBoard, Move, MoveList, Piece, Side, Square};
+
+use super::CheckInfo;
+
+//Runtime dispatch over the const generic API. Every method picks the
+//STM_WHITE/NSTM_WHITE pair from the stored side to move and forwards to the
+//const generic version, so both paths always produce identical results.
+impl ChessBoard {
+    #[inline]
+    pub fn map_legal_moves<F: FnMut(Move)>(&self, method: F) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_moves::<F, true, false>(method)
+        } else {
+            self.map_moves::<F, false, true>(method)
+        }
+    }
+
+    #[inline]
+    pub fn map_legal_captures<F: FnMut(Move)>(&self, method: F) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_captures::<F, true, false>(method)
+        } else {
+            self.map_captures::<F, false, true>(method)
+        }
+    }
+
+    #[inline]
+    pub fn map_legal_quiets<F: FnMut(Move)>(&self, method: F) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_quiets::<F, true, false>(method)
+        } else {
+            self.map_quiets::<F, false, true>(method)
+        }
+    }
+
+    #[inline]
+    pub fn map_legal_noisy<F: FnMut(Move)>(&self, method: F) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_noisy::<F, true, false>(method)
+        } else {
+            self.map_noisy::<F, false, true>(method)
+        }
+    }
+
+    #[inline]
+    pub fn map_legal_evasions<F: FnMut(Move)>(&self, method: F) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_evasions::<F, true, false>(method)
+        } else {
+            self.map_evasions::<F, false, true>(method)
+        }
+    }
+
+    #[inline]
+    pub fn map_legal_quiet_checks<F: FnMut(Move)>(&self, method: F) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_quiet_checks::<F, true, false>(method)
+        } else {
+            self.map_quiet_checks::<F, false, true>(method)
+        }
+    }
+
+    #[inline]
+    pub fn map_legal_moves_filtered<F: FnMut(Move)>(
+        &self,
+        pieces: &[Piece],
+        from_mask: Bitboard,
+        to_mask: Bitboard,
+        method: F,
+    ) {
+        if self.side_to_move() == Side::WHITE {
+            self.map_moves_filtered::<F, true, false>(pieces, from_mask, to_mask, method)
+        } else {
+            self.map_moves_filtered::<F, false, true>(pieces, from_mask, to_mask, method)
+        }
+    }
+
+    #[inline]
+    pub fn has_legal_moves(&self) -> bool {
+        if self.side_to_move() == Side::WHITE {
+            self.has_moves::<true, false>()
+        } else {
+            self.has_moves::<false, true>()
+        }
+    }
+
+    #[inline]
+    pub fn generate_legal_moves(&self, move_list: &mut MoveList) {
+        if self.side_to_move() == Side::WHITE {
+            self.generate_moves::<true, false>(move_list)
+        } else {
+            self.generate_moves::<false, true>(move_list)
+        }
+    }
+
+    #[inline]
+    pub fn generate_legal_captures(&self, move_list: &mut MoveList) {
+        if self.side_to_move() == Side::WHITE {
+            self.generate_captures::<true, false>(move_list)
+        } else {
+            self.generate_captures::<false, true>(move_list)
+        }
+    }
+
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut result = Vec::new();
+        self.map_legal_moves(|mv| result.push(mv));
+        result
+    }
+
+    pub fn legal_captures(&self) -> Vec<Move> {
+        let mut result = Vec::new();
+        self.map_legal_captures(|mv| result.push(mv));
+        result
+    }
+
+    #[inline]
+    pub fn is_pseudo_legal(&self, mv: Move) -> bool {
+        if self.side_to_move() == Side::WHITE {
+            self.is_move_pseudo_legal::<true, false>(mv)
+        } else {
+            self.is_move_pseudo_legal::<false, true>(mv)
+        }
+    }
+
+    #[inline]
+    pub fn is_legal(&self, mv: Move) -> bool {
+        if self.side_to_move() == Side::WHITE {
+            self.is_move_legal::<true, false>(mv)
+        } else {
+            self.is_move_legal::<false, true>(mv)
+        }
+    }
+
+    #[inline]
+    pub fn check_info(&self) -> CheckInfo {
+        if self.side_to_move() == Side::WHITE {
+            self.generate_check_info::<true, false>()
+        } else {
+            self.generate_check_info::<false, true>()
+        }
+    }
+
+    #[inline]
+    pub fn gives_check(&self, mv: Move) -> bool {
+        self.gives_check_with_info(mv, &self.check_info())
+    }
+
+    #[inline]
+    pub fn gives_check_with_info(&self, mv: Move, check_info: &CheckInfo) -> bool {
+        if self.side_to_move() == Side::WHITE {
+            self.move_gives_check::<true, false>(mv, check_info)
+        } else {
+            self.move_gives_check::<false, true>(mv, check_info)
+        }
+    }
+
+    #[inline]
+    pub fn play(&mut self, mv: Move) {
+        if self.side_to_move() == Side::WHITE {
+            self.make_move::<true, false>(mv)
+        } else {
+            self.make_move::<false, true>(mv)
+        }
+    }
+
+    #[inline]
+    pub fn in_check(&self) -> bool {
+        if self.side_to_move() == Side::WHITE {
+            self.is_in_check::<true, false>()
+        } else {
+            self.is_in_check::<false, true>()
+        }
+    }
+
+    #[inline]
+    pub fn checkers(&self) -> Bitboard {
+        if self.side_to_move() == Side::WHITE {
+            self.generate_checkers_mask::<true, false>()
+        } else {
+            self.generate_checkers_mask::<false, true>()
+        }
+    }
+
+    #[inline]
+    pub fn pin_masks(&self) -> (Bitboard, Bitboard) {
+        if self.side_to_move() == Side::WHITE {
+            self.generate_pin_masks::<true, false>()
+        } else {
+            self.generate_pin_masks::<false, true>()
+        }
+    }
+
+    #[inline]
+    pub fn attack_map(&self) -> Bitboard {
+        if self.side_to_move() == Side::WHITE {
+            self.generate_attack_map::<true, false>()
+        } else {
+            self.generate_attack_map::<false, true>()
+        }
+    }
+
+    #[inline]
+    pub fn king_square(&self, side: Side) -> Square {
+        if side == Side::WHITE {
+            self.get_king_square::<true>()
+        } else {
+            self.get_king_square::<false>()
+        }
+    }
+
+    #[inline]
+    pub fn occupancy_for_side(&self, side: Side) -> Bitboard {
+        if side == Side::WHITE {
+            self.get_occupancy_for_side::<true>()
+        } else {
+            self.get_occupancy_for_side::<false>()
+        }
+    }
+
+    #[inline]
+    pub fn piece_mask_for_side(&self, piece: Piece, side: Side) -> Bitboard {
+        self.get_piece_mask(piece) & self.occupancy_for_side(side)
+    }
+
+    #[inline]
+    pub fn attackers_to_square(
+        &self,
+        square: Square,
+        attacker: Side,
+        occupancy: Bitboard,
+    ) -> Bitboard {
+        if attacker == Side::WHITE {
+            self.all_attackers_to_square::<false, true>(occupancy, square)
+        } else {
+            self.all_attackers_to_square::<true, false>(occupancy, square)
+        }
+    }
+
+    #[inline]
+    pub fn is_square_attacked_by(&self, square: Square, attacker: Side) -> bool {
+        self.attackers_to_square(square, attacker, self.get_occupancy())
+            .is_not_empty()
+    }
+}
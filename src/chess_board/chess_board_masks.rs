@@ -3,9 +3,6 @@ use crate::{
     Bitboard, ChessBoard, Piece,
 };
 
-#[derive(Clone, Copy, Default)]
-pub struct ChessBoardMasks {}
-
 impl ChessBoard {
     #[inline]
     pub fn is_in_check<const DEFENDER_WHITE: bool, const ATTACKER_WHITE: bool>(&self) -> bool {
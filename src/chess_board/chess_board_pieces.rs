@@ -88,16 +88,16 @@ impl ChessBoard {
     #[inline]
     pub fn set_piece_on_square<const WHITE: bool>(&mut self, square: Square, piece: Piece) {
         self.pieces.set_piece_on_square::<WHITE>(square, piece);
+        let piece_count = self.get_piece_mask_for_side::<WHITE>(piece).pop_count();
         self.state
-            .get_key_mut()
-            .update_piece_hash::<WHITE>(piece, square)
+            .update_piece_keys::<WHITE>(piece, square, piece_count)
     }
 
     #[inline]
     pub fn remove_piece_on_square<const WHITE: bool>(&mut self, square: Square, piece: Piece) {
+        let piece_count = self.get_piece_mask_for_side::<WHITE>(piece).pop_count();
         self.pieces.remove_piece_on_square::<WHITE>(square, piece);
         self.state
-            .get_key_mut()
-            .update_piece_hash::<WHITE>(piece, square)
+            .update_piece_keys::<WHITE>(piece, square, piece_count)
     }
 }
@@ -5,22 +5,85 @@ use crate::{
 
 pub(super) const PHASE_VALUES: [u8; 6] = [0, 1, 1, 2, 4, 0];
 
-#[derive(Clone, Copy, Default, PartialEq, Hash)]
+const ALL_PIECES: [Piece; 6] = [
+    Piece::PAWN,
+    Piece::KNIGHT,
+    Piece::BISHOP,
+    Piece::ROOK,
+    Piece::QUEEN,
+    Piece::KING,
+];
+
+#[derive(Clone, Copy, PartialEq, Hash)]
 pub struct ChessBoardState {
     zobrist: ZobristKey,
+    pawn_key: ZobristKey,
+    material_key: ZobristKey,
+    non_pawn_keys: [ZobristKey; 2],
     half_moves: u8,
+    full_moves: u16,
+    ply: u16,
     en_passant: Square,
     side_to_move: Side,
     castle_rights: CastleRights,
+    castle_rook_squares: [Square; 4],
+    chess_960: bool,
     phase: u8,
 }
 
+impl Default for ChessBoardState {
+    fn default() -> Self {
+        Self {
+            zobrist: ZobristKey::default(),
+            pawn_key: ZobristKey::default(),
+            material_key: ZobristKey::default(),
+            non_pawn_keys: [ZobristKey::default(); 2],
+            half_moves: 0,
+            full_moves: 1,
+            ply: 0,
+            en_passant: Square::NULL,
+            side_to_move: Side::WHITE,
+            castle_rights: CastleRights::NULL,
+            castle_rook_squares: CastleRights::DEFAULT_ROOK_SQUARES,
+            chess_960: false,
+            phase: 0,
+        }
+    }
+}
+
 impl ChessBoardState {
+    #[inline]
+    pub(super) fn get_key(&self) -> ZobristKey {
+        self.zobrist
+    }
+
     #[inline]
     pub(super) fn get_key_mut(&mut self) -> &mut ZobristKey {
         &mut self.zobrist
     }
 
+    //Updates every key that depends on the piece. piece_count is the number of pieces of
+    //that type the side has while this one is on the board
+    #[inline]
+    pub(super) fn update_piece_keys<const WHITE: bool>(
+        &mut self,
+        piece: Piece,
+        square: Square,
+        piece_count: u32,
+    ) {
+        self.zobrist.update_piece_hash::<WHITE>(piece, square);
+        self.material_key
+            .update_material_hash::<WHITE>(piece, piece_count);
+
+        if piece == Piece::PAWN || piece == Piece::KING {
+            self.pawn_key.update_piece_hash::<WHITE>(piece, square);
+        }
+
+        if piece != Piece::PAWN {
+            self.non_pawn_keys[usize::from(!WHITE)].update_piece_hash::<WHITE>(piece, square);
+        }
+    }
+
     #[inline]
     pub(super) fn get_side_to_move_mut(&mut self) -> &mut Side {
         &mut self.side_to_move
@@ -36,11 +99,31 @@ impl ChessBoardState {
         &mut self.castle_rights
     }
 
+    #[inline]
+    pub(super) fn get_castle_rook_squares_mut(&mut self) -> &mut [Square; 4] {
+        &mut self.castle_rook_squares
+    }
+
+    #[inline]
+    pub(super) fn get_chess_960_mut(&mut self) -> &mut bool {
+        &mut self.chess_960
+    }
+
     #[inline]
     pub(super) fn get_half_move_counter_mut(&mut self) -> &mut u8 {
         &mut self.half_moves
     }
 
+    #[inline]
+    pub(super) fn get_full_move_counter_mut(&mut self) -> &mut u16 {
+        &mut self.full_moves
+    }
+
+    #[inline]
+    pub(super) fn get_ply_mut(&mut self) -> &mut u16 {
+        &mut self.ply
+    }
+
     #[inline]
     pub(super) fn get_phase_mut(&mut self) -> &mut u8 {
         &mut self.phase
@@ -60,6 +143,76 @@ impl ChessBoard {
             ^ (ZobristKey::get_side_to_move_seed() * self.side_to_move().get_raw() as u64)
     }
 
+    //Pawns and kings only, meant for pawn structure caches
+    #[inline]
+    pub fn get_pawn_key(&self) -> ZobristKey {
+        self.state.pawn_key
+    }
+
+    //Depends only on the number of pieces of each type per side
+    #[inline]
+    pub fn get_material_key(&self) -> ZobristKey {
+        self.state.material_key
+    }
+
+    //All pieces of the side except pawns, king included
+    #[inline]
+    pub fn get_non_pawn_key(&self, side: Side) -> ZobristKey {
+        self.state.non_pawn_keys[usize::from(side)]
+    }
+
+    //From scratch versions of the incrementally updated keys, meant for verification
+    pub fn calculate_key(&self) -> ZobristKey {
+        let mut key = self.calculate_piece_key(&ALL_PIECES, &[Side::WHITE, Side::BLACK]);
+
+        if self.en_passant_square() != Square::NULL {
+            key ^= ZobristKey::get_en_passant_seed(self.en_passant_square())
+        }
+
+        key ^ ZobristKey::get_castle_rights_seed(self.castle_rights().get_raw())
+            ^ (ZobristKey::get_side_to_move_seed() * self.side_to_move().get_raw() as u64)
+    }
+
+    pub fn calculate_pawn_key(&self) -> ZobristKey {
+        self.calculate_piece_key(&[Piece::PAWN, Piece::KING], &[Side::WHITE, Side::BLACK])
+    }
+
+    pub fn calculate_material_key(&self) -> ZobristKey {
+        let mut key = ZobristKey::NULL;
+        for side in [Side::WHITE, Side::BLACK] {
+            for piece in ALL_PIECES {
+                for count in 1..=self.piece_mask_for_side(piece, side).pop_count() {
+                    if side == Side::WHITE {
+                        key.update_material_hash::<true>(piece, count)
+                    } else {
+                        key.update_material_hash::<false>(piece, count)
+                    }
+                }
+            }
+        }
+        key
+    }
+
+    pub fn calculate_non_pawn_key(&self, side: Side) -> ZobristKey {
+        self.calculate_piece_key(&ALL_PIECES[1..], &[side])
+    }
+
+    fn calculate_piece_key(&self, pieces: &[Piece], sides: &[Side]) -> ZobristKey {
+        let mut key = ZobristKey::NULL;
+        for &side in sides {
+            for &piece in pieces {
+                self.piece_mask_for_side(piece, side).map(|square| {
+                    if side == Side::WHITE {
+                        key.update_piece_hash::<true>(piece, square)
+                    } else {
+                        key.update_piece_hash::<false>(piece, square)
+                    }
+                });
+            }
+        }
+        key
+    }
+
     #[inline]
     pub fn en_passant_square(&self) -> Square {
         self.state.en_passant
@@ -75,11 +228,48 @@ impl ChessBoard {
         self.state.castle_rights
     }
 
+    #[inline]
+    pub fn castle_rook_square(&self, right: CastleRights) -> Square {
+        self.state.castle_rook_squares[right.get_index()]
+    }
+
+    #[inline]
+    pub fn is_chess_960(&self) -> bool {
+        self.state.chess_960
+    }
+
+    #[inline]
+    pub fn set_chess_960(&mut self, chess_960: bool) {
+        self.state.chess_960 = chess_960
+    }
+
+    //Returns castle rights that are lost when a piece leaves or lands on the square
+    #[inline]
+    pub(crate) fn get_castle_rights_mask(&self, square: Square) -> u8 {
+        let mut mask = 0;
+        for (index, rook_square) in self.state.castle_rook_squares.iter().enumerate() {
+            if *rook_square == square {
+                mask |= 1 << index;
+            }
+        }
+        mask
+    }
+
     #[inline]
     pub fn half_move_counter(&self) -> u8 {
         self.state.half_moves
     }
 
+    #[inline]
+    pub fn full_move_counter(&self) -> u16 {
+        self.state.full_moves
+    }
+
+    #[inline]
+    pub fn ply(&self) -> u16 {
+        self.state.ply
+    }
+
     #[inline]
     pub fn get_phase(&self) -> u8 {
         self.state.phase
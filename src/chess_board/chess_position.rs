@@ -1,22 +1,13 @@
 use crate::{Move, MoveHistory, FEN};
 
-use super::ChessBoard;
+use super::{ChessBoard, UndoInfo};
 
-#[derive(Clone, Copy, Hash)]
+#[derive(Clone, Default, Hash)]
 pub struct ChessPosition {
     board: ChessBoard,
     history: MoveHistory,
 }
 
-impl Default for ChessPosition {
-    fn default() -> Self {
-        Self {
-            board: ChessBoard::default(),
-            history: MoveHistory::new(),
-        }
-    }
-}
-
 impl ChessPosition {
     #[inline]
     pub fn from_fen(fen: &FEN) -> Self {
@@ -48,14 +39,86 @@ impl ChessPosition {
     pub fn make_move<const STM_WHITE: bool, const NSTM_WHITE: bool>(&mut self, mv: Move) {
         self.history.push(self.board.get_key());
         self.board.make_move::<STM_WHITE, NSTM_WHITE>(mv);
+    }
 
-        if self.board.half_move_counter() == 0 {
-            self.history.reset()
-        }
+    #[inline]
+    pub fn play(&mut self, mv: Move) {
+        self.history.push(self.board.get_key());
+        self.board.play(mv);
+    }
+
+    #[inline]
+    pub fn make_move_with_undo<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &mut self,
+        mv: Move,
+    ) -> UndoInfo {
+        self.history.push(self.board.get_key());
+        self.board.make_move_with_undo::<STM_WHITE, NSTM_WHITE>(mv)
+    }
+
+    #[inline]
+    pub fn unmake_move<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &mut self,
+        mv: Move,
+        undo: UndoInfo,
+    ) {
+        self.board.unmake_move::<STM_WHITE, NSTM_WHITE>(mv, undo);
+        self.history.pop();
+    }
+
+    #[inline]
+    pub fn play_with_undo(&mut self, mv: Move) -> UndoInfo {
+        self.history.push(self.board.get_key());
+        self.board.play_with_undo(mv)
+    }
+
+    #[inline]
+    pub fn undo_move(&mut self, mv: Move, undo: UndoInfo) {
+        self.board.undo_move(mv, undo);
+        self.history.pop();
+    }
+
+    #[inline]
+    pub fn make_null_move(&mut self) -> UndoInfo {
+        self.history.push_null();
+        self.board.make_null_move()
+    }
+
+    #[inline]
+    pub fn unmake_null_move(&mut self, undo: UndoInfo) {
+        self.board.unmake_null_move(undo);
+        self.history.pop();
+    }
+
+    #[inline]
+    pub fn history(&self) -> &MoveHistory {
+        &self.history
+    }
+
+    //Call before starting a search, positions played from now on count as search moves
+    #[inline]
+    pub fn set_root(&mut self) {
+        self.history.set_root()
     }
 
     #[inline]
     pub fn is_repetition(&self) -> bool {
-        self.history.get_key_repetitions(self.board.get_key()) > 0
+        self.history
+            .get_key_repetitions(self.board.get_key(), self.board.half_move_counter())
+            > 0
+    }
+
+    //Repeating a position reached during the search is enough to score it as a draw
+    #[inline]
+    pub fn is_repetition_since_root(&self) -> bool {
+        self.history
+            .is_repetition_since_root(self.board.get_key(), self.board.half_move_counter())
+    }
+
+    #[inline]
+    pub fn is_threefold_repetition(&self) -> bool {
+        self.history
+            .get_key_repetitions(self.board.get_key(), self.board.half_move_counter())
+            >= 2
     }
 }
@@ -0,0 +1,37 @@
+use crate::Side;
+
+use super::ChessPosition;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameStatus {
+    Ongoing,
+    Checkmate(Side),
+    Stalemate,
+    FiftyMoveRule,
+    ThreefoldRepetition,
+    InsufficientMaterial,
+}
+
+impl ChessPosition {
+    //Mate and stalemate are checked first, so a mate delivered on the 100th half move still counts
+    pub fn game_status(&self) -> GameStatus {
+        let board = self.board();
+        if !board.has_legal_moves() {
+            return if board.in_check() {
+                GameStatus::Checkmate(board.side_to_move().flipped())
+            } else {
+                GameStatus::Stalemate
+            };
+        }
+
+        if board.half_move_counter() >= 100 {
+            GameStatus::FiftyMoveRule
+        } else if self.is_threefold_repetition() {
+            GameStatus::ThreefoldRepetition
+        } else if board.is_insufficient_material() {
+            GameStatus::InsufficientMaterial
+        } else {
+            GameStatus::Ongoing
+        }
+    }
+}
@@ -181,6 +181,12 @@ impl ChessBoard {
             *self.state.get_phase_mut() -= PHASE_VALUES[CAPTURED_PIECE as usize];
         }
 
+        //Castling rook is lifted first, so in Chess960 the king can land on its square
+        if MOVE_FLAG == MoveFlag::KING_SIDE_CASTLE || MOVE_FLAG == MoveFlag::QUEEN_SIDE_CASTLE {
+            let rook_square = self.castle_rook_square(castle_right::<STM_WHITE, MOVE_FLAG>());
+            self.remove_piece_on_square::<STM_WHITE>(rook_square, Piece::ROOK);
+        }
+
         self.remove_piece_on_square::<STM_WHITE>(from_square, Piece::from_raw(MOVED_PIECE));
         if MOVE_FLAG < MoveFlag::KNIGHT_PROMOTION {
             self.set_piece_on_square::<STM_WHITE>(to_square, Piece::from_raw(MOVED_PIECE));
@@ -193,9 +199,22 @@ impl ChessBoard {
         }
 
         let mut castle_rights = self.castle_rights().get_raw();
-        castle_rights &= !(CastleRights::ROOK_MASKS[from_square.get_raw() as usize]
-            | CastleRights::ROOK_MASKS[to_square.get_raw() as usize]);
-        *self.state.get_castle_rights_mut() = CastleRights::from_raw(castle_rights);
+        if castle_rights != 0 {
+            if MOVED_PIECE == KING {
+                castle_rights &= if STM_WHITE {
+                    !CastleRights::WHITE_RIGHTS.get_raw()
+                } else {
+                    !CastleRights::BLACK_RIGHTS.get_raw()
+                };
+            }
+            if MOVED_PIECE == ROOK {
+                castle_rights &= !self.get_castle_rights_mask(from_square);
+            }
+            if CAPTURED_PIECE == ROOK {
+                castle_rights &= !self.get_castle_rights_mask(to_square);
+            }
+            *self.state.get_castle_rights_mut() = CastleRights::from_raw(castle_rights);
+        }
 
         *self.state.get_en_passant_mut() = Square::NULL;
 
@@ -206,12 +225,7 @@ impl ChessBoard {
             MoveFlag::KING_SIDE_CASTLE | MoveFlag::QUEEN_SIDE_CASTLE => {
                 let king_side = usize::from(MOVE_FLAG == MoveFlag::KING_SIDE_CASTLE);
                 let side_flip = 56 * usize::from(!STM_WHITE) as u8;
-                let rook_from_square = side_flip + CastleRights::ROOK_POSITIONS[king_side];
                 let rook_to_square = side_flip + [3, 5][king_side];
-                self.remove_piece_on_square::<STM_WHITE>(
-                    Square::from_raw(rook_from_square),
-                    Piece::ROOK,
-                );
                 self.set_piece_on_square::<STM_WHITE>(
                     Square::from_raw(rook_to_square),
                     Piece::ROOK,
@@ -229,10 +243,25 @@ impl ChessBoard {
             _ => {}
         }
 
+        *self.state.get_ply_mut() += 1;
+        if !STM_WHITE {
+            *self.state.get_full_move_counter_mut() += 1;
+        }
+
         self.state.get_side_to_move_mut().mut_flip();
     }
 }
 
+#[inline]
+const fn castle_right<const STM_WHITE: bool, const MOVE_FLAG: u16>() -> CastleRights {
+    match (STM_WHITE, MOVE_FLAG == MoveFlag::KING_SIDE_CASTLE) {
+        (true, true) => CastleRights::WHITE_KING,
+        (true, false) => CastleRights::WHITE_QUEEN,
+        (false, true) => CastleRights::BLACK_KING,
+        (false, false) => CastleRights::BLACK_QUEEN,
+    }
+}
+
 const PAWN: u8 = 0;
 const KNIGHT: u8 = 1;
 const BISHOP: u8 = 2;
@@ -1,9 +1,23 @@
+mod board_transforms;
+mod check_info;
 mod chess_board_base;
+mod chess_board_dynamic;
 mod chess_board_masks;
 mod chess_board_pieces;
 mod chess_board_state;
 mod chess_position;
+mod game_status;
 mod make_move;
+mod move_notation;
+mod null_move;
+mod position_validation;
+mod static_exchange;
+mod unmake_move;
 
+pub use check_info::CheckInfo;
 pub use chess_board_base::ChessBoard;
 pub use chess_position::ChessPosition;
+pub use game_status::GameStatus;
+pub use move_notation::MoveParseError;
+pub use position_validation::PositionIssue;
+pub use unmake_move::UndoInfo;
@@ -0,0 +1,282 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{Bitboard, CastleRights, ChessBoard, Move, MoveFlag, Piece, Side, Square};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MoveParseError {
+    InvalidLength(usize),
+    InvalidSquare(String),
+    InvalidPromotion(char),
+    IllegalMove(String),
+    AmbiguousMove(String),
+    InvalidSan(String),
+}
+
+impl ChessBoard {
+    //Resolves a UCI move (e2e4, e7e8q, e1g1 or king takes rook e1h1) into a legal move of this position
+    pub fn parse_uci_move(&self, move_string: &str) -> std::result::Result<Move, MoveParseError> {
+        if !move_string.is_ascii() || !(4..=5).contains(&move_string.len()) {
+            return Err(MoveParseError::InvalidLength(move_string.chars().count()));
+        }
+
+        let from_square = Square::try_from_string(&move_string[0..2])
+            .ok_or_else(|| MoveParseError::InvalidSquare(move_string[0..2].to_string()))?;
+        let to_square = Square::try_from_string(&move_string[2..4])
+            .ok_or_else(|| MoveParseError::InvalidSquare(move_string[2..4].to_string()))?;
+        let promotion_piece = match move_string.chars().nth(4) {
+            None => Piece::NONE,
+            Some(promotion_char) => promotion_piece_from_char(promotion_char)
+                .ok_or(MoveParseError::InvalidPromotion(promotion_char))?,
+        };
+
+        //Regular moves take priority, so a king step onto the g or c file in Chess960
+        //is never mistaken for castling
+        let mut regular_move = None;
+        let mut castle_move = None;
+        self.map_legal_moves(|mv| {
+            if mv.get_from_square() != from_square {
+                return;
+            }
+
+            let flag = mv.get_flag();
+            if flag == MoveFlag::KING_SIDE_CASTLE || flag == MoveFlag::QUEEN_SIDE_CASTLE {
+                if promotion_piece == Piece::NONE
+                    && (mv.get_to_square() == to_square
+                        || self.castle_rook_square(self.castle_right_for_move(mv)) == to_square)
+                {
+                    castle_move = Some(mv);
+                }
+                return;
+            }
+
+            let move_promotion = if mv.is_promotion() {
+                mv.get_promotion_piece()
+            } else {
+                Piece::NONE
+            };
+            if mv.get_to_square() == to_square && move_promotion == promotion_piece {
+                regular_move = Some(mv);
+            }
+        });
+
+        regular_move
+            .or(castle_move)
+            .ok_or_else(|| MoveParseError::IllegalMove(move_string.to_string()))
+    }
+
+    pub fn move_to_san(&self, mv: Move) -> String {
+        let from_square = mv.get_from_square();
+        let to_square = mv.get_to_square();
+        let piece = self.get_piece_on_square(from_square);
+
+        let mut result = match mv.get_flag() {
+            MoveFlag::KING_SIDE_CASTLE => String::from("O-O"),
+            MoveFlag::QUEEN_SIDE_CASTLE => String::from("O-O-O"),
+            _ if piece == Piece::PAWN => {
+                let mut result = String::new();
+                if mv.is_capture() {
+                    result.push((b'a' + from_square.get_file()) as char);
+                    result.push('x');
+                }
+                result.push_str(&to_square.to_string());
+                if mv.is_promotion() {
+                    result.push('=');
+                    result.push(mv.get_promotion_piece().to_char().to_ascii_uppercase());
+                }
+                result
+            }
+            _ => {
+                let mut result = String::from(piece.to_char().to_ascii_uppercase());
+
+                let mut ambiguous = false;
+                let mut same_file = false;
+                let mut same_rank = false;
+                let from_mask = !from_square.get_bit();
+                let to_mask = to_square.get_bit();
+                self.map_legal_moves_filtered(&[piece], from_mask, to_mask, |other| {
+                    let other_from = other.get_from_square();
+                    if other.get_flag() == MoveFlag::KING_SIDE_CASTLE
+                        || other.get_flag() == MoveFlag::QUEEN_SIDE_CASTLE
+                    {
+                        return;
+                    }
+
+                    ambiguous = true;
+                    same_file |= other_from.get_file() == from_square.get_file();
+                    same_rank |= other_from.get_rank() == from_square.get_rank();
+                });
+
+                if ambiguous && (!same_file || same_rank) {
+                    result.push((b'a' + from_square.get_file()) as char);
+                }
+                if ambiguous && same_file {
+                    result.push((b'1' + from_square.get_rank()) as char);
+                }
+                if mv.is_capture() {
+                    result.push('x');
+                }
+                result.push_str(&to_square.to_string());
+                result
+            }
+        };
+
+        let mut board = *self;
+        board.play(mv);
+        if board.in_check() {
+            result.push(if !board.has_legal_moves() { '#' } else { '+' });
+        }
+
+        result
+    }
+
+    //Accepts common variations: missing or extra capture marks, 0-0 castling, promotion
+    //without '=', check marks and trailing annotations like !? or e.p.
+    pub fn parse_san(&self, san: &str) -> std::result::Result<Move, MoveParseError> {
+        let mut notation = san.trim();
+        notation = notation.strip_suffix("e.p.").unwrap_or(notation).trim_end();
+        notation = notation.trim_end_matches(['+', '#', '!', '?']);
+
+        if !notation.is_ascii() || notation.is_empty() {
+            return Err(MoveParseError::InvalidSan(san.to_string()));
+        }
+
+        let castle_flag = match notation {
+            "O-O" | "0-0" => Some(MoveFlag::KING_SIDE_CASTLE),
+            "O-O-O" | "0-0-0" => Some(MoveFlag::QUEEN_SIDE_CASTLE),
+            _ => None,
+        };
+        if let Some(castle_flag) = castle_flag {
+            let mut result = None;
+            self.map_legal_moves(|mv| {
+                if mv.get_flag() == castle_flag {
+                    result = Some(mv)
+                }
+            });
+            return result.ok_or_else(|| MoveParseError::IllegalMove(san.to_string()));
+        }
+
+        let mut chars: Vec<char> = notation
+            .chars()
+            .filter(|&character| !matches!(character, 'x' | ':' | '-' | '='))
+            .collect();
+        if chars.is_empty() {
+            return Err(MoveParseError::InvalidSan(san.to_string()));
+        }
+
+        let piece = match chars[0] {
+            'N' => Piece::KNIGHT,
+            'B' => Piece::BISHOP,
+            'R' => Piece::ROOK,
+            'Q' => Piece::QUEEN,
+            'K' => Piece::KING,
+            _ => Piece::PAWN,
+        };
+        if piece != Piece::PAWN {
+            chars.remove(0);
+        }
+
+        let mut promotion_piece = Piece::NONE;
+        if chars.len() >= 3 && chars[chars.len() - 2].is_ascii_digit() {
+            let promotion_char = chars.pop().unwrap_or_default();
+            promotion_piece = promotion_piece_from_char(promotion_char)
+                .ok_or(MoveParseError::InvalidPromotion(promotion_char))?;
+        }
+
+        if chars.len() < 2 || chars.len() > 4 {
+            return Err(MoveParseError::InvalidSan(san.to_string()));
+        }
+
+        let destination: String = chars[chars.len() - 2..].iter().collect();
+        let to_square = Square::try_from_string(&destination)
+            .ok_or_else(|| MoveParseError::InvalidSan(san.to_string()))?;
+
+        let mut from_file = None;
+        let mut from_rank = None;
+        for &character in &chars[..chars.len() - 2] {
+            match character {
+                'a'..='h' if from_file.is_none() => from_file = Some(character as u8 - b'a'),
+                '1'..='8' if from_rank.is_none() => from_rank = Some(character as u8 - b'1'),
+                _ => return Err(MoveParseError::InvalidSan(san.to_string())),
+            }
+        }
+
+        let mut from_mask = Bitboard::FULL;
+        if let Some(file) = from_file {
+            from_mask &= Bitboard::FILE_A << file as u32;
+        }
+        if let Some(rank) = from_rank {
+            from_mask &= Bitboard::RANK_1 << (rank * 8) as u32;
+        }
+
+        let mut result = None;
+        let mut matches = 0;
+        self.map_legal_moves_filtered(&[piece], from_mask, to_square.get_bit(), |mv| {
+            let move_promotion = if mv.is_promotion() {
+                mv.get_promotion_piece()
+            } else {
+                Piece::NONE
+            };
+
+            if mv.get_flag() == MoveFlag::KING_SIDE_CASTLE
+                || mv.get_flag() == MoveFlag::QUEEN_SIDE_CASTLE
+                || move_promotion != promotion_piece
+            {
+                return;
+            }
+
+            result = Some(mv);
+            matches += 1;
+        });
+
+        match (result, matches) {
+            (Some(mv), 1) => Ok(mv),
+            (None, _) => Err(MoveParseError::IllegalMove(san.to_string())),
+            _ => Err(MoveParseError::AmbiguousMove(san.to_string())),
+        }
+    }
+
+    pub(crate) fn castle_right_for_move(&self, mv: Move) -> CastleRights {
+        match (
+            self.side_to_move() == Side::WHITE,
+            mv.get_flag() == MoveFlag::KING_SIDE_CASTLE,
+        ) {
+            (true, true) => CastleRights::WHITE_KING,
+            (true, false) => CastleRights::WHITE_QUEEN,
+            (false, true) => CastleRights::BLACK_KING,
+            (false, false) => CastleRights::BLACK_QUEEN,
+        }
+    }
+}
+
+fn promotion_piece_from_char(promotion_char: char) -> Option<Piece> {
+    match promotion_char.to_ascii_lowercase() {
+        'n' => Some(Piece::KNIGHT),
+        'b' => Some(Piece::BISHOP),
+        'r' => Some(Piece::ROOK),
+        'q' => Some(Piece::QUEEN),
+        _ => None,
+    }
+}
+
+impl Display for MoveParseError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            MoveParseError::InvalidLength(length) => {
+                write!(formatter, "expected 4 or 5 characters, found {length}")
+            }
+            MoveParseError::InvalidSquare(square) => write!(formatter, "invalid square '{square}'"),
+            MoveParseError::InvalidPromotion(piece_char) => {
+                write!(formatter, "invalid promotion piece '{piece_char}'")
+            }
+            MoveParseError::IllegalMove(mv) => {
+                write!(formatter, "move '{mv}' is not legal in this position")
+            }
+            MoveParseError::AmbiguousMove(mv) => {
+                write!(formatter, "move '{mv}' matches more than one legal move")
+            }
+            MoveParseError::InvalidSan(san) => write!(formatter, "invalid SAN move '{san}'"),
+        }
+    }
+}
+
+impl std::error::Error for MoveParseError {}
@@ -0,0 +1,30 @@
+use crate::{ChessBoard, Piece, Side, Square};
+
+use super::UndoInfo;
+
+impl ChessBoard {
+    //Passes the turn. Zobrist key stays consistent with get_key, because side to move
+    //and en passant are hashed from the state on demand
+    pub fn make_null_move(&mut self) -> UndoInfo {
+        let undo = self.create_undo_info(Piece::NONE);
+
+        *self.state.get_en_passant_mut() = Square::NULL;
+        *self.state.get_half_move_counter_mut() += 1;
+        *self.state.get_ply_mut() += 1;
+        if self.side_to_move() == Side::BLACK {
+            *self.state.get_full_move_counter_mut() += 1;
+        }
+        self.state.get_side_to_move_mut().mut_flip();
+
+        undo
+    }
+
+    pub fn unmake_null_move(&mut self, undo: UndoInfo) {
+        self.state.get_side_to_move_mut().mut_flip();
+        if self.side_to_move() == Side::BLACK {
+            *self.state.get_full_move_counter_mut() -= 1;
+        }
+        *self.state.get_ply_mut() -= 1;
+        self.restore_undo_info(undo);
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{Attacks, Bitboard, CastleRights, ChessBoard, Piece, Side, Square};
+
+use super::chess_board_state::PHASE_VALUES;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PositionIssue {
+    MissingKing(Side),
+    TooManyKings(Side),
+    TooManyPieces(Side, u32),
+    PawnOnBackRank(Square),
+    OverlappingPieces(Square),
+    InconsistentOccupancy(Square),
+    ImpossibleCastleRights(CastleRights),
+    ImpossibleEnPassant(Square),
+    SideNotToMoveInCheck,
+    TooManyCheckers(u32),
+    ImpossibleCheck,
+    InvalidKey,
+    InvalidPhase(u8),
+}
+
+impl Display for PositionIssue {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            PositionIssue::MissingKing(side) => write!(formatter, "{side} has no king"),
+            PositionIssue::TooManyKings(side) => {
+                write!(formatter, "{side} has more than one king")
+            }
+            PositionIssue::TooManyPieces(side, count) => {
+                write!(
+                    formatter,
+                    "{side} has {count} pieces, at most 16 are possible"
+                )
+            }
+            PositionIssue::PawnOnBackRank(square) => {
+                write!(formatter, "pawn on the first or last rank at {square}")
+            }
+            PositionIssue::OverlappingPieces(square) => {
+                write!(formatter, "more than one piece on {square}")
+            }
+            PositionIssue::InconsistentOccupancy(square) => {
+                write!(formatter, "occupancy does not match the pieces on {square}")
+            }
+            PositionIssue::ImpossibleCastleRights(right) => write!(
+                formatter,
+                "castle right '{right}' has no king or rook on its home square"
+            ),
+            PositionIssue::ImpossibleEnPassant(square) => {
+                write!(
+                    formatter,
+                    "en passant square {square} is impossible in this position"
+                )
+            }
+            PositionIssue::SideNotToMoveInCheck => {
+                write!(formatter, "side not to move is in check")
+            }
+            PositionIssue::TooManyCheckers(count) => {
+                write!(formatter, "king is attacked by {count} pieces")
+            }
+            PositionIssue::ImpossibleCheck => {
+                write!(formatter, "no legal move could have given this check")
+            }
+            PositionIssue::InvalidKey => {
+                write!(formatter, "stored zobrist keys do not match the pieces")
+            }
+            PositionIssue::InvalidPhase(phase) => {
+                write!(formatter, "stored phase {phase} does not match the pieces")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PositionIssue {}
+
+impl ChessBoard {
+    //Checks the position for everything that cannot happen in a game, meant for hand built
+    //positions and data read from untrusted sources. Every issue found is reported
+    pub fn validate(&self) -> std::result::Result<(), Vec<PositionIssue>> {
+        let mut issues = Vec::new();
+
+        self.validate_pieces(&mut issues);
+
+        let kings_valid = [Side::WHITE, Side::BLACK]
+            .iter()
+            .all(|&side| self.piece_mask_for_side(Piece::KING, side).only_one_bit());
+        if kings_valid {
+            self.validate_castle_rights(&mut issues);
+            self.validate_en_passant(&mut issues);
+            self.validate_checks(&mut issues);
+        }
+
+        if self.get_key() != self.calculate_key()
+            || self.get_pawn_key() != self.calculate_pawn_key()
+            || self.get_material_key() != self.calculate_material_key()
+            || self.get_non_pawn_key(Side::WHITE) != self.calculate_non_pawn_key(Side::WHITE)
+            || self.get_non_pawn_key(Side::BLACK) != self.calculate_non_pawn_key(Side::BLACK)
+        {
+            issues.push(PositionIssue::InvalidKey);
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    fn validate_pieces(&self, issues: &mut Vec<PositionIssue>) {
+        let mut pieces = Bitboard::EMPTY;
+        let mut overlaps = Bitboard::EMPTY;
+        let mut phase = 0u8;
+        for piece_index in Piece::PAWN.get_raw()..=Piece::KING.get_raw() {
+            let piece = Piece::from_raw(piece_index);
+            let mask = self.get_piece_mask(piece);
+            overlaps |= pieces & mask;
+            pieces |= mask;
+            phase = phase.wrapping_add(
+                PHASE_VALUES[usize::from(piece)].wrapping_mul(mask.pop_count() as u8),
+            );
+        }
+
+        let white = self.occupancy_for_side(Side::WHITE);
+        let black = self.occupancy_for_side(Side::BLACK);
+        overlaps |= white & black;
+        overlaps.map(|square| issues.push(PositionIssue::OverlappingPieces(square)));
+        (pieces ^ (white | black))
+            .map(|square| issues.push(PositionIssue::InconsistentOccupancy(square)));
+
+        for side in [Side::WHITE, Side::BLACK] {
+            match self.piece_mask_for_side(Piece::KING, side).pop_count() {
+                0 => issues.push(PositionIssue::MissingKing(side)),
+                1 => {}
+                _ => issues.push(PositionIssue::TooManyKings(side)),
+            }
+
+            let piece_count = self.occupancy_for_side(side).pop_count();
+            if piece_count > 16 {
+                issues.push(PositionIssue::TooManyPieces(side, piece_count));
+            }
+        }
+
+        (self.get_piece_mask(Piece::PAWN) & (Bitboard::RANK_1 | Bitboard::RANK_8))
+            .map(|square| issues.push(PositionIssue::PawnOnBackRank(square)));
+
+        if phase != self.get_phase() {
+            issues.push(PositionIssue::InvalidPhase(self.get_phase()));
+        }
+    }
+
+    fn validate_castle_rights(&self, issues: &mut Vec<PositionIssue>) {
+        for (right, side, king_side) in [
+            (CastleRights::WHITE_KING, Side::WHITE, true),
+            (CastleRights::WHITE_QUEEN, Side::WHITE, false),
+            (CastleRights::BLACK_KING, Side::BLACK, true),
+            (CastleRights::BLACK_QUEEN, Side::BLACK, false),
+        ] {
+            if !self.castle_rights().has_right(right) {
+                continue;
+            }
+
+            let back_rank = if side == Side::WHITE { 0 } else { 7 };
+            let king_square = self.king_square(side);
+            let rook_square = self.castle_rook_square(right);
+            let rook_on_side = if king_side {
+                rook_square.get_file() > king_square.get_file()
+            } else {
+                rook_square.get_file() < king_square.get_file()
+            };
+
+            if king_square.get_rank() != back_rank
+                || rook_square.get_rank() != back_rank
+                || !rook_on_side
+                || !self
+                    .piece_mask_for_side(Piece::ROOK, side)
+                    .get_bit(rook_square)
+            {
+                issues.push(PositionIssue::ImpossibleCastleRights(right));
+            }
+        }
+    }
+
+    fn validate_en_passant(&self, issues: &mut Vec<PositionIssue>) {
+        let square = self.en_passant_square();
+        if square == Square::NULL {
+            return;
+        }
+
+        let expected_rank = if self.side_to_move() == Side::WHITE {
+            5
+        } else {
+            2
+        };
+        if square.get_raw() >= 64 || square.get_rank() != expected_rank {
+            issues.push(PositionIssue::ImpossibleEnPassant(square));
+            return;
+        }
+
+        let enemy_pawns = self.piece_mask_for_side(Piece::PAWN, self.side_to_move().flipped());
+        if self.get_occupancy().get_bit(square)
+            || self.get_occupancy().get_bit(square ^ 24)
+            || !enemy_pawns.get_bit(square ^ 8)
+        {
+            issues.push(PositionIssue::ImpossibleEnPassant(square));
+        }
+    }
+
+    //Last move can give at most one direct and one discovered check, so two checkers
+    //can't be two leapers or sliders sharing a line through the king. After a double push
+    //every checker has to be the pushed pawn or a slider revealed by it
+    fn validate_checks(&self, issues: &mut Vec<PositionIssue>) {
+        let side_to_move = self.side_to_move();
+        let enemy_king = self.king_square(side_to_move.flipped());
+        if self.is_square_attacked_by(enemy_king, side_to_move) {
+            issues.push(PositionIssue::SideNotToMoveInCheck);
+        }
+
+        let king_square = self.king_square(side_to_move);
+        let checkers =
+            self.attackers_to_square(king_square, side_to_move.flipped(), self.get_occupancy());
+        let checker_count = checkers.pop_count();
+        if checker_count > 2 {
+            issues.push(PositionIssue::TooManyCheckers(checker_count));
+            return;
+        }
+
+        let sliders = self.get_piece_mask(Piece::BISHOP)
+            | self.get_piece_mask(Piece::ROOK)
+            | self.get_piece_mask(Piece::QUEEN);
+        if checker_count == 2 {
+            let first = checkers.ls1b_square();
+            let second = checkers.exclude(first).ls1b_square();
+            if (checkers & sliders).is_empty()
+                || Attacks::line_through(first, second).get_bit(king_square)
+            {
+                issues.push(PositionIssue::ImpossibleCheck);
+                return;
+            }
+        }
+
+        let en_passant_square = self.en_passant_square();
+        if checkers.is_not_empty()
+            && en_passant_square != Square::NULL
+            && en_passant_square.get_raw() < 64
+        {
+            let pushed_pawn = en_passant_square ^ 8;
+            let origin = en_passant_square ^ 24;
+            let mut impossible = false;
+            checkers.map(|checker| {
+                if checker != pushed_pawn
+                    && !(sliders.get_bit(checker)
+                        && Attacks::between(king_square, checker).get_bit(origin))
+                {
+                    impossible = true;
+                }
+            });
+
+            if impossible {
+                issues.push(PositionIssue::ImpossibleCheck);
+            }
+        }
+    }
+}
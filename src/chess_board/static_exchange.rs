@@ -0,0 +1,209 @@
+use crate::{attacks::Attacks, Bitboard, ChessBoard, Move, MoveFlag, Piece, Side, Square};
+
+impl ChessBoard {
+    //Indexed by Piece, the king is never captured so its value does not matter
+    pub const SEE_VALUES: [i32; 6] = [100, 300, 300, 500, 900, 0];
+
+    #[inline]
+    pub fn see(&self, mv: Move) -> i32 {
+        self.see_with_values(mv, &Self::SEE_VALUES)
+    }
+
+    #[inline]
+    pub fn see_ge(&self, mv: Move, threshold: i32) -> bool {
+        self.see_ge_with_values(mv, threshold, &Self::SEE_VALUES)
+    }
+
+    //Material balance of the capture sequence on the target square, assuming both sides
+    //always recapture with their least valuable piece and may stop whenever it pays off
+    pub fn see_with_values(&self, mv: Move, values: &[i32; 6]) -> i32 {
+        if is_castle(mv) {
+            return 0;
+        }
+
+        let to_square = mv.get_to_square();
+        let (mut gains, mut piece_on_square) = initial_gain(self, mv, values);
+        let mut gain = [0; 32];
+        gain[0] = gains;
+
+        let mut exchange = ExchangeState::new(self, mv);
+        let mut depth = 0;
+        while let Some(piece) = exchange.next_attacker(self, to_square) {
+            depth += 1;
+            gains = values[usize::from(piece_on_square)] - gains;
+            gain[depth] = gains;
+            piece_on_square = piece;
+
+            if piece == Piece::KING {
+                break;
+            }
+        }
+
+        while depth > 0 {
+            gain[depth - 1] = -(-gain[depth - 1]).max(gain[depth]);
+            depth -= 1;
+        }
+        gain[0]
+    }
+
+    //Cheaper than comparing see() against the threshold, stops as soon as the outcome is decided
+    pub fn see_ge_with_values(&self, mv: Move, threshold: i32, values: &[i32; 6]) -> bool {
+        if is_castle(mv) {
+            return threshold <= 0;
+        }
+
+        let to_square = mv.get_to_square();
+        let (captured_value, piece_on_square) = initial_gain(self, mv, values);
+
+        let mut swap = captured_value - threshold;
+        if swap < 0 {
+            return false;
+        }
+
+        swap = values[usize::from(piece_on_square)] - swap;
+        if swap <= 0 {
+            return true;
+        }
+
+        let mut exchange = ExchangeState::new(self, mv);
+        let mut result = true;
+        while let Some(piece) = exchange.next_attacker(self, to_square) {
+            result = !result;
+            if piece == Piece::KING {
+                break;
+            }
+
+            swap = values[usize::from(piece)] - swap;
+            if swap < i32::from(result) {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+fn is_castle(mv: Move) -> bool {
+    mv.get_flag() == MoveFlag::KING_SIDE_CASTLE || mv.get_flag() == MoveFlag::QUEEN_SIDE_CASTLE
+}
+
+//Value won by the move itself and the piece left standing on the target square
+fn initial_gain(board: &ChessBoard, mv: Move, values: &[i32; 6]) -> (i32, Piece) {
+    let mut captured_value = if mv.is_en_passant() {
+        values[usize::from(Piece::PAWN)]
+    } else if mv.is_capture() {
+        values[usize::from(board.get_piece_on_square(mv.get_to_square()))]
+    } else {
+        0
+    };
+
+    let mut piece_on_square = board.get_piece_on_square(mv.get_from_square());
+    if mv.is_promotion() {
+        piece_on_square = mv.get_promotion_piece();
+        captured_value += values[usize::from(piece_on_square)] - values[usize::from(Piece::PAWN)];
+    }
+
+    (captured_value, piece_on_square)
+}
+
+struct ExchangeState {
+    occupancy: Bitboard,
+    attackers: Bitboard,
+    side: Side,
+    pinned: [Bitboard; 2],
+    pinners: [Bitboard; 2],
+}
+
+impl ExchangeState {
+    fn new(board: &ChessBoard, mv: Move) -> Self {
+        let to_square = mv.get_to_square();
+        let mut occupancy =
+            (board.get_occupancy() ^ mv.get_from_square().get_bit()) | to_square.get_bit();
+        if mv.is_en_passant() {
+            occupancy ^= (to_square ^ 8).get_bit();
+        }
+
+        let mut pinned = [Bitboard::EMPTY; 2];
+        let mut pinners = [Bitboard::EMPTY; 2];
+        for side in [Side::WHITE, Side::BLACK] {
+            let (diagonal_pins, ortographic_pins) = if side == Side::WHITE {
+                board.generate_pin_masks::<true, false>()
+            } else {
+                board.generate_pin_masks::<false, true>()
+            };
+            let pin_rays = diagonal_pins | ortographic_pins;
+            pinned[usize::from(side)] = pin_rays & board.occupancy_for_side(side);
+            pinners[usize::from(side)] = pin_rays & board.occupancy_for_side(side.flipped());
+        }
+
+        Self {
+            occupancy,
+            attackers: attackers_to(board, to_square, occupancy) & occupancy,
+            side: board.side_to_move(),
+            pinned,
+            pinners,
+        }
+    }
+
+    //Removes the least valuable attacker of the side to recapture and returns its type.
+    //A king only recaptures when the opponent has nothing left to take it back with.
+    fn next_attacker(&mut self, board: &ChessBoard, to_square: Square) -> Option<Piece> {
+        self.side.mut_flip();
+        let side_index = usize::from(self.side);
+
+        self.attackers &= self.occupancy;
+        let mut side_attackers = self.attackers & board.occupancy_for_side(self.side);
+        if (self.pinners[side_index] & self.occupancy).is_not_empty() {
+            side_attackers &= !self.pinned[side_index];
+        }
+
+        if side_attackers.is_empty() {
+            return None;
+        }
+
+        for piece_index in Piece::PAWN.get_raw()..=Piece::KING.get_raw() {
+            let piece = Piece::from_raw(piece_index);
+            let candidates = side_attackers & board.get_piece_mask(piece);
+            if candidates.is_empty() {
+                continue;
+            }
+
+            if piece == Piece::KING {
+                let enemy_attackers =
+                    self.attackers & board.occupancy_for_side(self.side.flipped());
+                return if enemy_attackers.is_empty() {
+                    Some(piece)
+                } else {
+                    None
+                };
+            }
+
+            self.occupancy ^= candidates.ls1b_square().get_bit();
+            self.attackers |= slider_attackers(board, to_square, self.occupancy);
+            return Some(piece);
+        }
+
+        None
+    }
+}
+
+fn attackers_to(board: &ChessBoard, square: Square, occupancy: Bitboard) -> Bitboard {
+    let pawns = board.get_piece_mask(Piece::PAWN);
+    (Attacks::get_pawn_attacks_for_square::<true>(square)
+        & pawns
+        & board.occupancy_for_side(Side::BLACK))
+        | (Attacks::get_pawn_attacks_for_square::<false>(square)
+            & pawns
+            & board.occupancy_for_side(Side::WHITE))
+        | (Attacks::get_knight_attacks_for_square(square) & board.get_piece_mask(Piece::KNIGHT))
+        | (Attacks::get_king_attacks_for_square(square) & board.get_piece_mask(Piece::KING))
+        | slider_attackers(board, square, occupancy)
+}
+
+fn slider_attackers(board: &ChessBoard, square: Square, occupancy: Bitboard) -> Bitboard {
+    let queens = board.get_piece_mask(Piece::QUEEN);
+    (Attacks::get_bishop_attacks_for_square(square, occupancy)
+        & (board.get_piece_mask(Piece::BISHOP) | queens))
+        | (Attacks::get_rook_attacks_for_square(square, occupancy)
+            & (board.get_piece_mask(Piece::ROOK) | queens))
+}
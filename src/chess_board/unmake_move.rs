@@ -0,0 +1,128 @@
+use crate::{CastleRights, ChessBoard, Move, MoveFlag, Piece, Side, Square, ZobristKey};
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct UndoInfo {
+    key: ZobristKey,
+    captured_piece: Piece,
+    castle_rights: CastleRights,
+    en_passant: Square,
+    half_moves: u8,
+    phase: u8,
+}
+
+impl UndoInfo {
+    #[inline]
+    pub fn captured_piece(&self) -> Piece {
+        self.captured_piece
+    }
+}
+
+impl ChessBoard {
+    #[inline]
+    pub fn make_move_with_undo<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &mut self,
+        mv: Move,
+    ) -> UndoInfo {
+        let captured_piece = if mv.is_en_passant() {
+            Piece::PAWN
+        } else if mv.is_capture() {
+            self.get_piece_on_square(mv.get_to_square())
+        } else {
+            Piece::NONE
+        };
+
+        let undo = self.create_undo_info(captured_piece);
+        self.make_move::<STM_WHITE, NSTM_WHITE>(mv);
+        undo
+    }
+
+    #[inline]
+    pub(super) fn create_undo_info(&self, captured_piece: Piece) -> UndoInfo {
+        UndoInfo {
+            key: self.state.get_key(),
+            captured_piece,
+            castle_rights: self.castle_rights(),
+            en_passant: self.en_passant_square(),
+            half_moves: self.half_move_counter(),
+            phase: self.get_phase(),
+        }
+    }
+
+    #[inline]
+    pub(super) fn restore_undo_info(&mut self, undo: UndoInfo) {
+        *self.state.get_key_mut() = undo.key;
+        *self.state.get_castle_rights_mut() = undo.castle_rights;
+        *self.state.get_en_passant_mut() = undo.en_passant;
+        *self.state.get_half_move_counter_mut() = undo.half_moves;
+        *self.state.get_phase_mut() = undo.phase;
+    }
+
+    //STM_WHITE is the side that made the move being taken back
+    pub fn unmake_move<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &mut self,
+        mv: Move,
+        undo: UndoInfo,
+    ) {
+        let from_square = mv.get_from_square();
+        let to_square = mv.get_to_square();
+
+        match mv.get_flag() {
+            MoveFlag::KING_SIDE_CASTLE | MoveFlag::QUEEN_SIDE_CASTLE => {
+                let king_side = mv.get_flag() == MoveFlag::KING_SIDE_CASTLE;
+                let right = match (STM_WHITE, king_side) {
+                    (true, true) => CastleRights::WHITE_KING,
+                    (true, false) => CastleRights::WHITE_QUEEN,
+                    (false, true) => CastleRights::BLACK_KING,
+                    (false, false) => CastleRights::BLACK_QUEEN,
+                };
+                let side_flip = 56 * u8::from(!STM_WHITE);
+                let rook_to_square = Square::from_raw(side_flip + [3, 5][usize::from(king_side)]);
+
+                self.remove_piece_on_square::<STM_WHITE>(to_square, Piece::KING);
+                self.remove_piece_on_square::<STM_WHITE>(rook_to_square, Piece::ROOK);
+                self.set_piece_on_square::<STM_WHITE>(self.castle_rook_square(right), Piece::ROOK);
+                self.set_piece_on_square::<STM_WHITE>(from_square, Piece::KING);
+            }
+            MoveFlag::KNIGHT_PROMOTION.. => {
+                self.remove_piece_on_square::<STM_WHITE>(to_square, mv.get_promotion_piece());
+                self.set_piece_on_square::<STM_WHITE>(from_square, Piece::PAWN);
+            }
+            _ => {
+                let moved_piece = self.get_piece_on_square(to_square);
+                self.remove_piece_on_square::<STM_WHITE>(to_square, moved_piece);
+                self.set_piece_on_square::<STM_WHITE>(from_square, moved_piece);
+            }
+        }
+
+        if mv.is_en_passant() {
+            self.set_piece_on_square::<NSTM_WHITE>(to_square ^ 8, Piece::PAWN);
+        } else if undo.captured_piece != Piece::NONE {
+            self.set_piece_on_square::<NSTM_WHITE>(to_square, undo.captured_piece);
+        }
+
+        self.restore_undo_info(undo);
+        *self.state.get_ply_mut() -= 1;
+        if !STM_WHITE {
+            *self.state.get_full_move_counter_mut() -= 1;
+        }
+        self.state.get_side_to_move_mut().mut_flip();
+    }
+
+    #[inline]
+    pub fn play_with_undo(&mut self, mv: Move) -> UndoInfo {
+        if self.side_to_move() == Side::WHITE {
+            self.make_move_with_undo::<true, false>(mv)
+        } else {
+            self.make_move_with_undo::<false, true>(mv)
+        }
+    }
+
+    #[inline]
+    pub fn undo_move(&mut self, mv: Move, undo: UndoInfo) {
+        if self.side_to_move() == Side::WHITE {
+            self.unmake_move::<false, true>(mv, undo)
+        } else {
+            self.unmake_move::<true, false>(mv, undo)
+        }
+    }
+}
@@ -4,6 +4,8 @@ mod binpacks;
 mod chess_board;
 mod move_gen;
 mod perft;
+mod pgn;
+mod polyglot;
 mod utils;
 
 #[allow(unused)]
@@ -13,18 +15,28 @@ pub use base_structures::Bitboard;
 #[allow(unused)]
 pub use base_structures::CastleRights;
 #[allow(unused)]
+pub use base_structures::Direction;
+#[allow(unused)]
 pub use base_structures::Move;
 #[allow(unused)]
 pub use base_structures::MoveFlag;
 #[allow(unused)]
 pub use base_structures::MoveHistory;
 #[allow(unused)]
+pub use base_structures::MoveList;
+#[allow(unused)]
 pub use base_structures::Piece;
 #[allow(unused)]
 pub use base_structures::Side;
 #[allow(unused)]
 pub use base_structures::Square;
 #[allow(unused)]
+pub use base_structures::EpdError;
+#[allow(unused)]
+pub use base_structures::FenError;
+#[allow(unused)]
+pub use base_structures::EPD;
+#[allow(unused)]
 pub use base_structures::FEN;
 #[allow(unused)]
 pub use base_structures::ZobristKey;
@@ -33,10 +45,38 @@ pub use binpacks::ChessBoardPacked;
 #[allow(unused)]
 pub use binpacks::PolicyPacked;
 #[allow(unused)]
+pub use chess_board::CheckInfo;
+#[allow(unused)]
 pub use chess_board::ChessBoard;
 #[allow(unused)]
 pub use chess_board::ChessPosition;
 #[allow(unused)]
+pub use chess_board::GameStatus;
+#[allow(unused)]
+pub use chess_board::MoveParseError;
+#[allow(unused)]
+pub use chess_board::PositionIssue;
+#[allow(unused)]
+pub use chess_board::UndoInfo;
+#[allow(unused)]
 pub use perft::Perft;
 #[allow(unused)]
+pub use pgn::GameResult;
+#[allow(unused)]
+pub use pgn::PgnError;
+#[allow(unused)]
+pub use pgn::PgnGame;
+#[allow(unused)]
+pub use pgn::PgnReader;
+#[allow(unused)]
+pub use pgn::PgnWriter;
+#[allow(unused)]
+pub use polyglot::BookMove;
+#[allow(unused)]
+pub use polyglot::PolyglotBook;
+#[allow(unused)]
+pub use polyglot::PolyglotBookBuilder;
+#[allow(unused)]
+pub use polyglot::PolyglotEntry;
+#[allow(unused)]
 pub use utils::StringUtils;
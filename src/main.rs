@@ -50,6 +50,69 @@ fn main() {
         }
     }
 
+    println!("\nMake/unmake:");
+    for (index, fen) in fens.clone().into_iter().enumerate() {
+        let (result_nodes, result_duration) =
+            Perft::perft_make_unmake::<true, false, false>(&fen.0, fen.1);
+        if result_nodes != fen.3 {
+            println!(
+                "Position {index} has failed in {result_duration}ms ({}nps)",
+                result_nodes * 1000 / result_duration
+            );
+        } else {
+            println!(
+                "Position {index} has passed in {result_duration}ms ({}nps)",
+                result_nodes * 1000 / result_duration
+            );
+        }
+    }
+
+    let chess_960_fens = [
+        (
+            FEN::from_str("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9"),
+            5,
+            8146062,
+        ),
+        (
+            FEN::from_str("2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9"),
+            5,
+            16253601,
+        ),
+        (
+            FEN::from_str("b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9"),
+            5,
+            6417013,
+        ),
+        (
+            FEN::from_str("qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9"),
+            5,
+            9183776,
+        ),
+        (
+            FEN::from_str("1nbbnrkr/p1p1ppp1/3p4/1p3P1p/3Pq2P/8/PPP1P1P1/QNBBNRKR w HFhf - 0 9"),
+            5,
+            34030312,
+        ),
+        (
+            FEN::from_str("qnbnr1kr/ppp1b1pp/4p3/3p1p2/8/2NPP3/PPP1BPPP/QNB1R1KR w HEhe - 1 9"),
+            5,
+            24851983,
+        ),
+    ];
+
+    println!("\nChess960:");
+    for (index, fen) in chess_960_fens.iter().enumerate() {
+        let (result_nodes, result_duration) = Perft::perft::<true, false, false>(&fen.0, fen.1);
+        println!(
+            "Position {index} has {} in {result_duration}ms",
+            if result_nodes == fen.2 {
+                "passed"
+            } else {
+                "failed"
+            }
+        );
+    }
+
     println!("\nNon-bulk:");
     for fen in &fens {
         let mut nodes = 0u128;
@@ -1,13 +1,30 @@
-use crate::{attacks::Rays, Bitboard, ChessBoard, Move};
+use std::cell::Cell;
+
+use crate::{attacks::Attacks, Bitboard, ChessBoard, Move, MoveList, Piece};
 
 pub struct MoveGen;
+impl MoveGen {
+    //Generator modes, combined as bits. Each legal move belongs to exactly one of them,
+    //quiets being every non capture except a quiet promotion to a queen
+    pub const CAPTURES: u8 = 1;
+    pub const QUIET_QUEEN_PROMOTIONS: u8 = 2;
+    pub const QUIETS: u8 = 4;
+    pub const NOISY: u8 = Self::CAPTURES | Self::QUIET_QUEEN_PROMOTIONS;
+    pub const ALL_MOVES: u8 = Self::NOISY | Self::QUIETS;
+}
+
 impl ChessBoard {
     #[inline]
     pub fn map_moves<F: FnMut(Move), const STM_WHITE: bool, const NSTM_WHITE: bool>(
         &self,
         mut method: F,
     ) {
-        Self::map_moves_internal::<F, false, STM_WHITE, NSTM_WHITE>(self, &mut method)
+        Self::map_moves_internal::<F, { MoveGen::ALL_MOVES }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut method,
+        )
     }
 
     #[inline]
@@ -15,16 +32,243 @@ impl ChessBoard {
         &self,
         mut method: F,
     ) {
-        Self::map_moves_internal::<F, true, STM_WHITE, NSTM_WHITE>(self, &mut method)
+        Self::map_moves_internal::<F, { MoveGen::CAPTURES }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut method,
+        )
+    }
+
+    //Non captures apart from quiet queen promotions, castling included
+    #[inline]
+    pub fn map_quiets<F: FnMut(Move), const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mut method: F,
+    ) {
+        Self::map_moves_internal::<F, { MoveGen::QUIETS }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut method,
+        )
+    }
+
+    //Captures, en passant and every promotion to a queen, the complement of map_quiets
+    #[inline]
+    pub fn map_noisy<F: FnMut(Move), const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mut method: F,
+    ) {
+        Self::map_moves_internal::<F, { MoveGen::NOISY }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut method,
+        )
+    }
+
+    //All legal moves while in check, nothing otherwise
+    #[inline]
+    pub fn map_evasions<F: FnMut(Move), const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mut method: F,
+    ) {
+        if self.is_in_check::<STM_WHITE, NSTM_WHITE>() {
+            Self::map_moves_internal::<F, { MoveGen::ALL_MOVES }, STM_WHITE, NSTM_WHITE>(
+                self,
+                Bitboard::FULL,
+                Bitboard::FULL,
+                &mut method,
+            )
+        }
+    }
+
+    //Subset of map_quiets that gives check, meant for the first ply of quiescence search
+    pub fn map_quiet_checks<F: FnMut(Move), const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mut method: F,
+    ) {
+        let check_info = self.generate_check_info::<STM_WHITE, NSTM_WHITE>();
+        Self::map_moves_internal::<_, { MoveGen::QUIETS }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut |mv| {
+                if self.move_gives_check::<STM_WHITE, NSTM_WHITE>(mv, &check_info) {
+                    method(mv)
+                }
+            },
+        )
+    }
+
+    //Legal moves of the given piece types that start on from_mask and land on to_mask.
+    //Masks are applied inside the generators, so nothing outside of them is generated
+    pub fn map_moves_filtered<F: FnMut(Move), const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        pieces: &[Piece],
+        from_mask: Bitboard,
+        to_mask: Bitboard,
+        mut method: F,
+    ) {
+        let mut piece_mask = Bitboard::EMPTY;
+        for &piece in pieces {
+            piece_mask |= self.get_piece_mask_for_side::<STM_WHITE>(piece);
+        }
+
+        Self::map_moves_internal::<F, { MoveGen::ALL_MOVES }, STM_WHITE, NSTM_WHITE>(
+            self,
+            from_mask & piece_mask,
+            to_mask,
+            &mut method,
+        )
+    }
+
+    //Replaces the content of the list with the legal moves of the position
+    #[inline]
+    pub fn generate_moves<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        move_list: &mut MoveList,
+    ) {
+        move_list.clear();
+        Self::map_moves_internal::<_, { MoveGen::ALL_MOVES }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut |mv| move_list.push(mv),
+        )
+    }
+
+    #[inline]
+    pub fn generate_captures<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        move_list: &mut MoveList,
+    ) {
+        move_list.clear();
+        Self::map_moves_internal::<_, { MoveGen::CAPTURES }, STM_WHITE, NSTM_WHITE>(
+            self,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            &mut |mv| move_list.push(mv),
+        )
+    }
+
+    //Runs the generators one piece type at a time and stops as soon as any legal move shows up
+    pub fn has_moves<const STM_WHITE: bool, const NSTM_WHITE: bool>(&self) -> bool {
+        let attack_map = self.generate_attack_map::<STM_WHITE, NSTM_WHITE>();
+        let king_square = self.get_king_square::<STM_WHITE>();
+
+        let found = Cell::new(false);
+        let method = &mut |_| found.set(true);
+
+        MoveGen::generate_king_moves::<_, { MoveGen::ALL_MOVES }, NSTM_WHITE>(
+            self,
+            attack_map,
+            king_square,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            method,
+        );
+        if found.get() {
+            return true;
+        }
+
+        let checkers = if self.is_square_attacked_with_attack_map(king_square, attack_map) {
+            self.generate_checkers_mask::<STM_WHITE, NSTM_WHITE>()
+        } else {
+            Bitboard::EMPTY
+        };
+
+        let (push_map, capture_map) = if checkers.is_empty() {
+            (
+                !self.get_occupancy(),
+                self.get_occupancy_for_side::<NSTM_WHITE>(),
+            )
+        } else if checkers.only_one_bit() {
+            let checker = checkers.ls1b_square();
+            (Attacks::between(king_square, checker), checkers)
+        } else {
+            return false;
+        };
+
+        let (diagonal_pins, ortographic_pins) = self.generate_pin_masks::<STM_WHITE, NSTM_WHITE>();
+        MoveGen::generate_piece_moves::<_, { MoveGen::ALL_MOVES }, { MoveGen::KNIGHT }, STM_WHITE>(
+            self,
+            push_map,
+            capture_map,
+            diagonal_pins,
+            ortographic_pins,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            method,
+        );
+        if found.get() {
+            return true;
+        }
+
+        MoveGen::generate_piece_moves::<_, { MoveGen::ALL_MOVES }, { MoveGen::BISHOP }, STM_WHITE>(
+            self,
+            push_map,
+            capture_map,
+            diagonal_pins,
+            ortographic_pins,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            method,
+        );
+        if found.get() {
+            return true;
+        }
+
+        MoveGen::generate_piece_moves::<_, { MoveGen::ALL_MOVES }, { MoveGen::ROOK }, STM_WHITE>(
+            self,
+            push_map,
+            capture_map,
+            diagonal_pins,
+            ortographic_pins,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            method,
+        );
+        if found.get() {
+            return true;
+        }
+
+        MoveGen::generate_pawn_moves::<_, STM_WHITE, NSTM_WHITE, { MoveGen::ALL_MOVES }>(
+            self,
+            push_map,
+            capture_map,
+            diagonal_pins,
+            ortographic_pins,
+            Bitboard::FULL,
+            Bitboard::FULL,
+            method,
+        );
+        if found.get() {
+            return true;
+        }
+
+        if checkers.is_empty() {
+            MoveGen::generate_castle_moves::<_, STM_WHITE>(
+                self,
+                attack_map,
+                king_square,
+                Bitboard::FULL,
+                method,
+            );
+        }
+        found.get()
     }
 
     fn map_moves_internal<
         F: FnMut(Move),
-        const CAPTURE_ONLY: bool,
+        const MODE: u8,
         const STM_WHITE: bool,
         const NSTM_WHITE: bool,
     >(
         &self,
+        from_mask: Bitboard,
+        to_mask: Bitboard,
         method: &mut F,
     ) {
         let attack_map = self.generate_attack_map::<STM_WHITE, NSTM_WHITE>();
@@ -36,19 +280,22 @@ impl ChessBoard {
             Bitboard::EMPTY
         };
 
-        MoveGen::generate_king_moves::<F, CAPTURE_ONLY, NSTM_WHITE>(
+        MoveGen::generate_king_moves::<F, MODE, NSTM_WHITE>(
             self,
             attack_map,
             king_square,
+            from_mask,
+            to_mask,
             method,
         );
 
         if checkers.is_empty() {
-            if !CAPTURE_ONLY {
+            if MODE & MoveGen::QUIETS != 0 && from_mask.get_bit(king_square) {
                 MoveGen::generate_castle_moves::<F, STM_WHITE>(
                     self,
                     attack_map,
                     king_square,
+                    to_mask,
                     method,
                 )
             }
@@ -56,72 +303,88 @@ impl ChessBoard {
             let push_map = !self.get_occupancy();
             let capture_map = self.get_occupancy_for_side::<NSTM_WHITE>();
 
-            MoveGen::generate_pawn_moves::<F, STM_WHITE, NSTM_WHITE, CAPTURE_ONLY>(
+            MoveGen::generate_pawn_moves::<F, STM_WHITE, NSTM_WHITE, MODE>(
                 self,
                 push_map,
                 capture_map,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
-            MoveGen::generate_piece_moves::<F, CAPTURE_ONLY, { MoveGen::KNIGHT }, STM_WHITE>(
+            MoveGen::generate_piece_moves::<F, MODE, { MoveGen::KNIGHT }, STM_WHITE>(
                 self,
                 push_map,
                 capture_map,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
-            MoveGen::generate_piece_moves::<F, CAPTURE_ONLY, { MoveGen::BISHOP }, STM_WHITE>(
+            MoveGen::generate_piece_moves::<F, MODE, { MoveGen::BISHOP }, STM_WHITE>(
                 self,
                 push_map,
                 capture_map,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
-            MoveGen::generate_piece_moves::<F, CAPTURE_ONLY, { MoveGen::ROOK }, STM_WHITE>(
+            MoveGen::generate_piece_moves::<F, MODE, { MoveGen::ROOK }, STM_WHITE>(
                 self,
                 push_map,
                 capture_map,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
         } else if (checkers & (checkers - 1)).is_empty() {
             let checker = checkers.ls1b_square();
-            let push_map = Rays::get_ray(king_square, checker).exclude(checker);
+            let push_map = Attacks::between(king_square, checker);
 
-            MoveGen::generate_pawn_moves::<F, STM_WHITE, NSTM_WHITE, CAPTURE_ONLY>(
+            MoveGen::generate_pawn_moves::<F, STM_WHITE, NSTM_WHITE, MODE>(
                 self,
                 push_map,
                 checkers,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
-            MoveGen::generate_piece_moves::<F, CAPTURE_ONLY, { MoveGen::KNIGHT }, STM_WHITE>(
+            MoveGen::generate_piece_moves::<F, MODE, { MoveGen::KNIGHT }, STM_WHITE>(
                 self,
                 push_map,
                 checkers,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
-            MoveGen::generate_piece_moves::<F, CAPTURE_ONLY, { MoveGen::BISHOP }, STM_WHITE>(
+            MoveGen::generate_piece_moves::<F, MODE, { MoveGen::BISHOP }, STM_WHITE>(
                 self,
                 push_map,
                 checkers,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
-            MoveGen::generate_piece_moves::<F, CAPTURE_ONLY, { MoveGen::ROOK }, STM_WHITE>(
+            MoveGen::generate_piece_moves::<F, MODE, { MoveGen::ROOK }, STM_WHITE>(
                 self,
                 push_map,
                 checkers,
                 diagonal_pins,
                 ortographic_pins,
+                from_mask,
+                to_mask,
                 method,
             );
         }
@@ -1,23 +1,31 @@
 use crate::{
     attacks::{Attacks, Rays},
-    Bitboard, CastleRights, ChessBoard, Move, MoveFlag, Square,
+    Bitboard, CastleRights, ChessBoard, Move, MoveFlag, Piece, Square,
 };
 
 use super::MoveGen;
 
 impl MoveGen {
-    pub fn generate_king_moves<F: FnMut(Move), const CAPTURE_ONLY: bool, const NSTM_WHITE: bool>(
+    pub fn generate_king_moves<F: FnMut(Move), const MODE: u8, const NSTM_WHITE: bool>(
         board: &ChessBoard,
         attack_map: Bitboard,
         king_square: Square,
+        from_mask: Bitboard,
+        to_mask: Bitboard,
         method: &mut F,
     ) {
-        let move_mask = Attacks::get_king_attacks_for_square(king_square) & !attack_map;
+        if !from_mask.get_bit(king_square) {
+            return;
+        }
 
-        (move_mask & board.get_occupancy_for_side::<NSTM_WHITE>())
-            .map(|square| method(Move::from_squares(king_square, square, MoveFlag::CAPTURE)));
+        let move_mask = Attacks::get_king_attacks_for_square(king_square) & !attack_map & to_mask;
 
-        if CAPTURE_ONLY {
+        if MODE & MoveGen::CAPTURES != 0 {
+            (move_mask & board.get_occupancy_for_side::<NSTM_WHITE>())
+                .map(|square| method(Move::from_squares(king_square, square, MoveFlag::CAPTURE)));
+        }
+
+        if MODE & MoveGen::QUIETS == 0 {
             return;
         }
 
@@ -34,73 +42,83 @@ impl MoveGen {
         board: &ChessBoard,
         attack_map: Bitboard,
         king_square: Square,
+        to_mask: Bitboard,
         method: &mut F,
     ) {
-        let king_side_destination = (king_square.get_bit() << 2).ls1b_square();
-        let queen_side_destination = (king_square.get_bit() >> 2).ls1b_square();
-
-        if STM_WHITE {
-            let king_side_room = Rays::get_ray(
-                king_square,
-                Square::from_raw(CastleRights::ROOK_POSITIONS[1]).shift_right(1),
-            ) & board.get_occupancy();
-            let queen_side_room = Rays::get_ray(
-                king_square,
-                Square::from_raw(CastleRights::ROOK_POSITIONS[0]).shift_left(1),
-            ) & board.get_occupancy();
-            if board.castle_rights().has_right(CastleRights::WHITE_KING)
-                && (Rays::get_ray(king_square, king_side_destination) & attack_map).is_empty()
-                && king_side_room.is_empty()
-            {
-                method(Move::from_squares(
-                    king_square,
-                    king_side_destination,
-                    MoveFlag::KING_SIDE_CASTLE,
-                ))
-            }
-            if board.castle_rights().has_right(CastleRights::WHITE_QUEEN)
-                && (Rays::get_ray(king_square, queen_side_destination) & attack_map).is_empty()
-                && queen_side_room.is_empty()
-            {
-                method(Move::from_squares(
-                    king_square,
-                    queen_side_destination,
-                    MoveFlag::QUEEN_SIDE_CASTLE,
-                ))
-            }
+        let (king_side_right, queen_side_right) = if STM_WHITE {
+            (CastleRights::WHITE_KING, CastleRights::WHITE_QUEEN)
         } else {
-            let king_side_room = Rays::get_ray(
+            (CastleRights::BLACK_KING, CastleRights::BLACK_QUEEN)
+        };
+        let side_flip = if STM_WHITE { 0 } else { 56 };
+
+        if board.castle_rights().has_right(king_side_right)
+            && to_mask.get_bit(Square::from_raw(side_flip + 6))
+        {
+            generate_castle_move::<F, STM_WHITE>(
+                board,
+                attack_map,
                 king_square,
-                Square::from_raw(CastleRights::ROOK_POSITIONS[1])
-                    .flip()
-                    .shift_right(1),
-            ) & board.get_occupancy();
-            let queen_side_room = Rays::get_ray(
+                board.castle_rook_square(king_side_right),
+                Square::from_raw(side_flip + 6),
+                Square::from_raw(side_flip + 5),
+                MoveFlag::KING_SIDE_CASTLE,
+                method,
+            )
+        }
+
+        if board.castle_rights().has_right(queen_side_right)
+            && to_mask.get_bit(Square::from_raw(side_flip + 2))
+        {
+            generate_castle_move::<F, STM_WHITE>(
+                board,
+                attack_map,
                 king_square,
-                Square::from_raw(CastleRights::ROOK_POSITIONS[0])
-                    .flip()
-                    .shift_left(1),
-            ) & board.get_occupancy();
-            if board.castle_rights().has_right(CastleRights::BLACK_KING)
-                && (Rays::get_ray(king_square, king_side_destination) & attack_map).is_empty()
-                && king_side_room.is_empty()
-            {
-                method(Move::from_squares(
-                    king_square,
-                    king_side_destination,
-                    MoveFlag::KING_SIDE_CASTLE,
-                ))
-            }
-            if board.castle_rights().has_right(CastleRights::BLACK_QUEEN)
-                && (Rays::get_ray(king_square, queen_side_destination) & attack_map).is_empty()
-                && queen_side_room.is_empty()
-            {
-                method(Move::from_squares(
-                    king_square,
-                    queen_side_destination,
-                    MoveFlag::QUEEN_SIDE_CASTLE,
-                ))
-            }
+                board.castle_rook_square(queen_side_right),
+                Square::from_raw(side_flip + 2),
+                Square::from_raw(side_flip + 3),
+                MoveFlag::QUEEN_SIDE_CASTLE,
+                method,
+            )
         }
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+fn generate_castle_move<F: FnMut(Move), const STM_WHITE: bool>(
+    board: &ChessBoard,
+    attack_map: Bitboard,
+    king_square: Square,
+    rook_square: Square,
+    king_destination: Square,
+    rook_destination: Square,
+    flag: u16,
+    method: &mut F,
+) {
+    //King and castling rook may freely pass through and land on each other's squares
+    let occupancy = board.get_occupancy() ^ king_square.get_bit() ^ rook_square.get_bit();
+    let king_path = Rays::get_ray(king_square, king_destination);
+    let rook_path = Rays::get_ray(rook_square, rook_destination);
+    let required_room =
+        king_path | rook_path | king_destination.get_bit() | rook_destination.get_bit();
+
+    if (occupancy & required_room).is_not_empty() || (king_path & attack_map).is_not_empty() {
+        return;
+    }
+
+    //In Chess960 the castling rook can shield the king destination from a slider on the back rank
+    let enemy_orthographic_sliders = if STM_WHITE {
+        board.get_occupancy_for_side::<false>()
+    } else {
+        board.get_occupancy_for_side::<true>()
+    } & (board.get_piece_mask(Piece::ROOK)
+        | board.get_piece_mask(Piece::QUEEN));
+    if (Attacks::get_rook_attacks_for_square(king_destination, occupancy)
+        & enemy_orthographic_sliders)
+        .is_not_empty()
+    {
+        return;
+    }
+
+    method(Move::from_squares(king_square, king_destination, flag))
+}
@@ -1,5 +1,6 @@
 mod generate_moves;
 mod king_moves;
+mod move_validation;
 mod pawn_moves;
 mod piece_moves;
 
@@ -0,0 +1,178 @@
+use crate::{attacks::Attacks, Bitboard, ChessBoard, Move, MoveFlag, Piece, Square};
+
+use super::MoveGen;
+
+impl ChessBoard {
+    //Checks if a raw move follows the movement rules of the piece on its from square.
+    //King safety is only verified for castling, everything else is left for is_move_legal
+    pub fn is_move_pseudo_legal<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mv: Move,
+    ) -> bool {
+        let from_square = mv.get_from_square();
+        let to_square = mv.get_to_square();
+        let flag = mv.get_flag();
+
+        if !self
+            .get_occupancy_for_side::<STM_WHITE>()
+            .get_bit(from_square)
+        {
+            return false;
+        }
+
+        let piece = self.get_piece_on_square(from_square);
+        match flag {
+            MoveFlag::KING_SIDE_CASTLE | MoveFlag::QUEEN_SIDE_CASTLE => {
+                return piece == Piece::KING
+                    && self.is_castle_move_valid::<STM_WHITE, NSTM_WHITE>(mv)
+            }
+            //Flags 6 and 7 are not used by the move encoding
+            6 | 7 => return false,
+            _ => {}
+        }
+
+        if mv.is_capture() && !mv.is_en_passant() {
+            if !self
+                .get_occupancy_for_side::<NSTM_WHITE>()
+                .get_bit(to_square)
+                || self.get_piece_on_square(to_square) == Piece::KING
+            {
+                return false;
+            }
+        } else if self.get_occupancy().get_bit(to_square) {
+            return false;
+        }
+
+        if piece == Piece::PAWN {
+            return self.is_pawn_move_pseudo_legal::<STM_WHITE, NSTM_WHITE>(mv);
+        }
+
+        if flag != MoveFlag::QUIET_MOVE && flag != MoveFlag::CAPTURE {
+            return false;
+        }
+
+        let attacks = match piece {
+            Piece::KNIGHT => Attacks::get_knight_attacks_for_square(from_square),
+            Piece::BISHOP => {
+                Attacks::get_bishop_attacks_for_square(from_square, self.get_occupancy())
+            }
+            Piece::ROOK => Attacks::get_rook_attacks_for_square(from_square, self.get_occupancy()),
+            Piece::QUEEN => {
+                Attacks::get_bishop_attacks_for_square(from_square, self.get_occupancy())
+                    | Attacks::get_rook_attacks_for_square(from_square, self.get_occupancy())
+            }
+            _ => Attacks::get_king_attacks_for_square(from_square),
+        };
+
+        attacks.get_bit(to_square)
+    }
+
+    //Full legality check for a raw move, safe to call on stale or corrupted moves
+    pub fn is_move_legal<const STM_WHITE: bool, const NSTM_WHITE: bool>(&self, mv: Move) -> bool {
+        if !self.is_move_pseudo_legal::<STM_WHITE, NSTM_WHITE>(mv) {
+            return false;
+        }
+
+        let flag = mv.get_flag();
+        if flag == MoveFlag::KING_SIDE_CASTLE || flag == MoveFlag::QUEEN_SIDE_CASTLE {
+            return true;
+        }
+
+        let from_square = mv.get_from_square();
+        let to_square = mv.get_to_square();
+        let king_square = self.get_king_square::<STM_WHITE>();
+
+        if from_square == king_square {
+            let occupancy = self.get_occupancy() ^ from_square.get_bit();
+            return !self
+                .is_square_attacked_with_occupancy::<STM_WHITE, NSTM_WHITE>(to_square, occupancy);
+        }
+
+        //Captured piece no longer attacks anything, and the vacated squares may open slider lines
+        let mut occupancy = (self.get_occupancy() ^ from_square.get_bit()) | to_square.get_bit();
+        let mut captured_pieces = to_square.get_bit();
+        if mv.is_en_passant() {
+            occupancy ^= (to_square ^ 8).get_bit();
+            captured_pieces |= (to_square ^ 8).get_bit();
+        }
+
+        (self.all_attackers_to_square::<STM_WHITE, NSTM_WHITE>(occupancy, king_square)
+            & !captured_pieces)
+            .is_empty()
+    }
+
+    fn is_pawn_move_pseudo_legal<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mv: Move,
+    ) -> bool {
+        let from_square = mv.get_from_square();
+        let to_square = mv.get_to_square();
+        let flag = mv.get_flag();
+
+        let promotion_rank = if STM_WHITE {
+            Bitboard::RANK_8
+        } else {
+            Bitboard::RANK_1
+        };
+        if mv.is_promotion() != promotion_rank.get_bit(to_square) {
+            return false;
+        }
+
+        let single_push = if STM_WHITE {
+            from_square.get_raw().wrapping_add(8)
+        } else {
+            from_square.get_raw().wrapping_sub(8)
+        };
+
+        match flag {
+            MoveFlag::EN_PASSANT => {
+                to_square == self.en_passant_square()
+                    && Attacks::get_pawn_attacks_for_square::<STM_WHITE>(from_square)
+                        .get_bit(to_square)
+            }
+            MoveFlag::DOUBLE_PUSH => {
+                let double_push_rank = if STM_WHITE {
+                    Bitboard::RANK_2
+                } else {
+                    Bitboard::RANK_7
+                };
+                let double_push = if STM_WHITE {
+                    from_square.get_raw() + 16
+                } else {
+                    from_square.get_raw().wrapping_sub(16)
+                };
+
+                double_push_rank.get_bit(from_square)
+                    && to_square.get_raw() == double_push
+                    && !self.get_occupancy().get_bit(Square::from_raw(single_push))
+            }
+            _ if mv.is_capture() => {
+                Attacks::get_pawn_attacks_for_square::<STM_WHITE>(from_square).get_bit(to_square)
+            }
+            _ => to_square.get_raw() == single_push,
+        }
+    }
+
+    fn is_castle_move_valid<const STM_WHITE: bool, const NSTM_WHITE: bool>(
+        &self,
+        mv: Move,
+    ) -> bool {
+        let king_square = self.get_king_square::<STM_WHITE>();
+        let attack_map = self.generate_attack_map::<STM_WHITE, NSTM_WHITE>();
+        if mv.get_from_square() != king_square
+            || self.is_square_attacked_with_attack_map(king_square, attack_map)
+        {
+            return false;
+        }
+
+        let mut found = false;
+        MoveGen::generate_castle_moves::<_, STM_WHITE>(
+            self,
+            attack_map,
+            king_square,
+            Bitboard::FULL,
+            &mut |castle_move| found |= castle_move == mv,
+        );
+        found
+    }
+}
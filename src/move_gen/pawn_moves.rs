@@ -3,43 +3,50 @@ use crate::{attacks::Attacks, Bitboard, ChessBoard, Move, MoveFlag, Piece, Squar
 use super::MoveGen;
 
 impl MoveGen {
+    #[allow(clippy::too_many_arguments)]
     pub fn generate_pawn_moves<
         F: FnMut(Move),
         const STM_WHITE: bool,
         const NSTM_WHITE: bool,
-        const CAPTURE_ONLY: bool,
+        const MODE: u8,
     >(
         board: &ChessBoard,
         push_map: Bitboard,
         capture_map: Bitboard,
         diagonal_pins: Bitboard,
         ortographic_pins: Bitboard,
+        from_mask: Bitboard,
+        to_mask: Bitboard,
         method: &mut F,
     ) {
         let promotion_rank = if STM_WHITE { Bitboard::RANK_7 } else { Bitboard::RANK_2 };
         let double_push_rank = if STM_WHITE { Bitboard::RANK_2 } else { Bitboard::RANK_7 };
-        let pawns = board.get_piece_mask_for_side::<STM_WHITE>(Piece::PAWN);
+        let pawns = board.get_piece_mask_for_side::<STM_WHITE>(Piece::PAWN) & from_mask;
+        let push_map = push_map & to_mask;
+        let capture_map = capture_map & to_mask;
 
         let pushable_pawns = pawns & !diagonal_pins;
         let attack_pawns = pawns & !ortographic_pins;
 
-        handle_pawn_captures::<F, STM_WHITE>(
-            attack_pawns,
-            capture_map,
-            diagonal_pins,
-            promotion_rank,
-            method,
-        );
-
-        if board.en_passant_square() != Square::NULL {
-            handle_en_passant::<F, STM_WHITE, NSTM_WHITE>(board, attack_pawns, method)
+        if MODE & MoveGen::CAPTURES != 0 {
+            handle_pawn_captures::<F, STM_WHITE>(
+                attack_pawns,
+                capture_map,
+                diagonal_pins,
+                promotion_rank,
+                method,
+            );
+
+            if board.en_passant_square() != Square::NULL && to_mask.get_bit(board.en_passant_square()) {
+                handle_en_passant::<F, STM_WHITE, NSTM_WHITE>(board, attack_pawns, method)
+            }
         }
 
-        if CAPTURE_ONLY {
+        if MODE & (MoveGen::QUIETS | MoveGen::QUIET_QUEEN_PROMOTIONS) == 0 {
             return;
         }
 
-        handle_pawn_pushes::<F, STM_WHITE>(
+        handle_pawn_pushes::<F, STM_WHITE, MODE>(
             board,
             pushable_pawns,
             push_map,
@@ -51,7 +58,7 @@ impl MoveGen {
     }
 }
 
-fn handle_pawn_pushes<F: FnMut(Move), const STM_WHITE: bool>(
+fn handle_pawn_pushes<F: FnMut(Move), const STM_WHITE: bool, const MODE: u8>(
     board: &ChessBoard,
     pushable_pawns: Bitboard,
     push_map: Bitboard,
@@ -72,25 +79,31 @@ fn handle_pawn_pushes<F: FnMut(Move), const STM_WHITE: bool>(
     let double_shifted_push_map = if STM_WHITE { push_map.shift_right(16) } else { push_map.shift_left(16) };
     let single_shifted_empty = if STM_WHITE { empty_spaces.shift_right(8) } else { empty_spaces.shift_left(8) };
 
-    let single_push_pawns = pushable_pawns & !promotion_pawns & single_shifted_push_map;
-    let targets = if STM_WHITE { single_push_pawns.shift_left(8) } else { single_push_pawns.shift_right(8) };
-    for (from_square, to_square) in single_push_pawns.into_iter().zip(targets) {
-        method(Move::from_squares(from_square, to_square, MoveFlag::QUIET_MOVE))
-    }
+    if MODE & MoveGen::QUIETS != 0 {
+        let single_push_pawns = pushable_pawns & !promotion_pawns & single_shifted_push_map;
+        let targets = if STM_WHITE { single_push_pawns.shift_left(8) } else { single_push_pawns.shift_right(8) };
+        for (from_square, to_square) in single_push_pawns.into_iter().zip(targets) {
+            method(Move::from_squares(from_square, to_square, MoveFlag::QUIET_MOVE))
+        }
 
-    let double_push_pawns = double_pushable_pawns & single_shifted_empty & double_shifted_push_map;
-    let targets = if STM_WHITE { double_push_pawns.shift_left(16) } else { double_push_pawns.shift_right(16) };
-    for (from_square, to_square) in double_push_pawns.into_iter().zip(targets) {
-        method(Move::from_squares(from_square, to_square, MoveFlag::DOUBLE_PUSH))
+        let double_push_pawns = double_pushable_pawns & single_shifted_empty & double_shifted_push_map;
+        let targets = if STM_WHITE { double_push_pawns.shift_left(16) } else { double_push_pawns.shift_right(16) };
+        for (from_square, to_square) in double_push_pawns.into_iter().zip(targets) {
+            method(Move::from_squares(from_square, to_square, MoveFlag::DOUBLE_PUSH))
+        }
     }
 
     let promotion_pawns = promotion_pawns & single_shifted_push_map;
     let targets = if STM_WHITE { promotion_pawns.shift_left(8) } else { promotion_pawns.shift_right(8) };
     for (from_square, to_square) in promotion_pawns.into_iter().zip(targets) {
-        method(Move::from_squares(from_square, to_square, MoveFlag::KNIGHT_PROMOTION));
-        method(Move::from_squares(from_square, to_square, MoveFlag::BISHOP_PROMOTION));
-        method(Move::from_squares(from_square, to_square, MoveFlag::ROOK_PROMOTION));
-        method(Move::from_squares(from_square, to_square, MoveFlag::QUEEN_PROMOTION));
+        if MODE & MoveGen::QUIETS != 0 {
+            method(Move::from_squares(from_square, to_square, MoveFlag::KNIGHT_PROMOTION));
+            method(Move::from_squares(from_square, to_square, MoveFlag::BISHOP_PROMOTION));
+            method(Move::from_squares(from_square, to_square, MoveFlag::ROOK_PROMOTION));
+        }
+        if MODE & MoveGen::QUIET_QUEEN_PROMOTIONS != 0 {
+            method(Move::from_squares(from_square, to_square, MoveFlag::QUEEN_PROMOTION));
+        }
     }
 }
 
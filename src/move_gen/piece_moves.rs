@@ -7,9 +7,10 @@ impl MoveGen {
     pub const BISHOP: i8 = 1;
     pub const ROOK: i8 = 2;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn generate_piece_moves<
         F: FnMut(Move),
-        const CAPTURE_ONLY: bool,
+        const MODE: u8,
         const PIECE_TYPE: i8,
         const STM_WHITE: bool,
     >(
@@ -18,26 +19,31 @@ impl MoveGen {
         capture_map: Bitboard,
         diagonal_pins: Bitboard,
         ortographic_pins: Bitboard,
+        from_mask: Bitboard,
+        to_mask: Bitboard,
         method: &mut F,
     ) {
-        let pieces = match PIECE_TYPE {
-            MoveGen::KNIGHT => {
-                board.get_piece_mask_for_side::<STM_WHITE>(Piece::KNIGHT)
-                    & !diagonal_pins
-                    & !ortographic_pins
-            }
-            MoveGen::BISHOP => {
-                (board.get_piece_mask_for_side::<STM_WHITE>(Piece::BISHOP)
-                    | board.get_piece_mask_for_side::<STM_WHITE>(Piece::QUEEN))
-                    & !ortographic_pins
-            }
-            MoveGen::ROOK => {
-                (board.get_piece_mask_for_side::<STM_WHITE>(Piece::ROOK)
-                    | board.get_piece_mask_for_side::<STM_WHITE>(Piece::QUEEN))
-                    & !diagonal_pins
-            }
-            _ => unreachable!(),
-        };
+        let push_map = push_map & to_mask;
+        let capture_map = capture_map & to_mask;
+        let pieces = from_mask
+            & match PIECE_TYPE {
+                MoveGen::KNIGHT => {
+                    board.get_piece_mask_for_side::<STM_WHITE>(Piece::KNIGHT)
+                        & !diagonal_pins
+                        & !ortographic_pins
+                }
+                MoveGen::BISHOP => {
+                    (board.get_piece_mask_for_side::<STM_WHITE>(Piece::BISHOP)
+                        | board.get_piece_mask_for_side::<STM_WHITE>(Piece::QUEEN))
+                        & !ortographic_pins
+                }
+                MoveGen::ROOK => {
+                    (board.get_piece_mask_for_side::<STM_WHITE>(Piece::ROOK)
+                        | board.get_piece_mask_for_side::<STM_WHITE>(Piece::QUEEN))
+                        & !diagonal_pins
+                }
+                _ => unreachable!(),
+            };
 
         let pinned_pieces = match PIECE_TYPE {
             MoveGen::KNIGHT => Bitboard::EMPTY,
@@ -60,15 +66,17 @@ impl MoveGen {
                 _ => unreachable!(),
             };
 
-            (attacks & capture_map).map(|to_square| {
-                method(Move::from_squares(
-                    piece_square,
-                    to_square,
-                    MoveFlag::CAPTURE,
-                ))
-            });
+            if MODE & MoveGen::CAPTURES != 0 {
+                (attacks & capture_map).map(|to_square| {
+                    method(Move::from_squares(
+                        piece_square,
+                        to_square,
+                        MoveFlag::CAPTURE,
+                    ))
+                });
+            }
 
-            if CAPTURE_ONLY {
+            if MODE & MoveGen::QUIETS == 0 {
                 return;
             }
 
@@ -95,15 +103,17 @@ impl MoveGen {
                 _ => unreachable!(),
             };
 
-            (attacks & capture_map).map(|to_square| {
-                method(Move::from_squares(
-                    piece_square,
-                    to_square,
-                    MoveFlag::CAPTURE,
-                ))
-            });
+            if MODE & MoveGen::CAPTURES != 0 {
+                (attacks & capture_map).map(|to_square| {
+                    method(Move::from_squares(
+                        piece_square,
+                        to_square,
+                        MoveFlag::CAPTURE,
+                    ))
+                });
+            }
 
-            if CAPTURE_ONLY {
+            if MODE & MoveGen::QUIETS == 0 {
                 return;
             }
 
@@ -1,6 +1,6 @@
 use std::time::Instant;
 
-use crate::{base_structures::Side, ChessBoard, StringUtils, FEN};
+use crate::{base_structures::Side, ChessBoard, Move, StringUtils, FEN};
 
 pub struct Perft;
 impl Perft {
@@ -8,7 +8,26 @@ impl Perft {
         fen: &FEN,
         depth: u8,
     ) -> (u128, u128) {
-        let board = ChessBoard::from_fen(fen);
+        Self::perft_with_mode::<BULK, SPLIT, PRINT, false>(fen, depth)
+    }
+
+    pub fn perft_make_unmake<const BULK: bool, const SPLIT: bool, const PRINT: bool>(
+        fen: &FEN,
+        depth: u8,
+    ) -> (u128, u128) {
+        Self::perft_with_mode::<BULK, SPLIT, PRINT, true>(fen, depth)
+    }
+
+    fn perft_with_mode<
+        const BULK: bool,
+        const SPLIT: bool,
+        const PRINT: bool,
+        const MAKE_UNMAKE: bool,
+    >(
+        fen: &FEN,
+        depth: u8,
+    ) -> (u128, u128) {
+        let mut board = ChessBoard::from_fen(fen);
 
         if PRINT {
             let pext = {
@@ -29,15 +48,23 @@ impl Perft {
             println!("  Depth: {depth}");
             println!("  Split: {SPLIT}");
             println!("  Bulk: {BULK}");
+            println!("  Make/Unmake: {MAKE_UNMAKE}");
             println!("  PEXT: {pext}",);
             println!("-----------------------------------------------------------");
         }
 
         let timer = Instant::now();
-        let result = if board.side_to_move() == Side::WHITE {
-            perft_internal::<BULK, SPLIT, PRINT, true, true, false>(&board, depth)
-        } else {
-            perft_internal::<BULK, SPLIT, PRINT, true, false, true>(&board, depth)
+        let result = match (MAKE_UNMAKE, board.side_to_move() == Side::WHITE) {
+            (false, true) => perft_internal::<BULK, SPLIT, PRINT, true, true, false>(&board, depth),
+            (false, false) => {
+                perft_internal::<BULK, SPLIT, PRINT, true, false, true>(&board, depth)
+            }
+            (true, true) => perft_internal_make_unmake::<BULK, SPLIT, PRINT, true, true, false>(
+                &mut board, depth,
+            ),
+            (true, false) => perft_internal_make_unmake::<BULK, SPLIT, PRINT, true, false, true>(
+                &mut board, depth,
+            ),
         };
         let duration = timer.elapsed().as_millis();
 
@@ -98,3 +125,47 @@ fn perft_internal<
 
     node_count
 }
+
+fn perft_internal_make_unmake<
+    const BULK: bool,
+    const SPLIT: bool,
+    const PRINT: bool,
+    const FIRST: bool,
+    const STM_WHITE: bool,
+    const NSTM_WHITE: bool,
+>(
+    board: &mut ChessBoard,
+    depth: u8,
+) -> u128 {
+    if !BULK && depth == 0 {
+        return 1;
+    }
+
+    let mut moves = [Move::NULL; 218];
+    let mut move_count = 0;
+    board.map_moves::<_, STM_WHITE, NSTM_WHITE>(|mv| {
+        moves[move_count] = mv;
+        move_count += 1;
+    });
+
+    if BULK && depth == 1 {
+        return move_count as u128;
+    }
+
+    let mut node_count = 0u128;
+    for &mv in &moves[..move_count] {
+        let undo = board.make_move_with_undo::<STM_WHITE, NSTM_WHITE>(mv);
+        let result = perft_internal_make_unmake::<BULK, SPLIT, PRINT, false, NSTM_WHITE, STM_WHITE>(
+            board,
+            depth - 1,
+        );
+        board.unmake_move::<STM_WHITE, NSTM_WHITE>(mv, undo);
+        node_count += result;
+
+        if SPLIT && PRINT && FIRST {
+            println!("{mv} - {result}")
+        }
+    }
+
+    node_count
+}
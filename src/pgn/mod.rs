@@ -0,0 +1,7 @@
+mod pgn_game;
+mod pgn_reader;
+mod pgn_writer;
+
+pub use pgn_game::{GameResult, PgnGame};
+pub use pgn_reader::{PgnError, PgnReader};
+pub use pgn_writer::PgnWriter;
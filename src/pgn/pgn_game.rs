@@ -0,0 +1,89 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{ChessBoard, Move, FEN};
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+    #[default]
+    Unknown,
+}
+
+impl GameResult {
+    pub fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "1-0" => Some(GameResult::WhiteWins),
+            "0-1" => Some(GameResult::BlackWins),
+            "1/2-1/2" => Some(GameResult::Draw),
+            "*" => Some(GameResult::Unknown),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameResult::WhiteWins => "1-0",
+            GameResult::BlackWins => "0-1",
+            GameResult::Draw => "1/2-1/2",
+            GameResult::Unknown => "*",
+        }
+    }
+}
+
+impl Display for GameResult {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct PgnGame {
+    pub(super) headers: Vec<(String, String)>,
+    pub(super) start_position: ChessBoard,
+    pub(super) moves: Vec<Move>,
+    pub(super) result: GameResult,
+}
+
+impl PgnGame {
+    #[inline]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[inline]
+    pub fn start_position(&self) -> &ChessBoard {
+        &self.start_position
+    }
+
+    #[inline]
+    pub fn start_fen(&self) -> FEN {
+        self.start_position.get_fen()
+    }
+
+    #[inline]
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    #[inline]
+    pub fn result(&self) -> GameResult {
+        self.result
+    }
+
+    pub fn final_position(&self) -> ChessBoard {
+        let mut board = self.start_position;
+        for &mv in &self.moves {
+            board.play(mv);
+        }
+        board
+    }
+}
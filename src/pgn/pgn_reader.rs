@@ -0,0 +1,364 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    io::BufRead,
+};
+
+use crate::{ChessBoard, FenError, MoveParseError, Side, FEN};
+
+use super::{GameResult, PgnGame};
+
+#[derive(Debug)]
+pub enum PgnError {
+    Io(std::io::Error),
+    InvalidTag {
+        line: usize,
+        text: String,
+    },
+    InvalidFen {
+        line: usize,
+        error: FenError,
+    },
+    IllegalMove {
+        line: usize,
+        move_number: u16,
+        side: Side,
+        san: String,
+        error: MoveParseError,
+    },
+    UnbalancedVariation {
+        line: usize,
+    },
+    UnterminatedComment {
+        line: usize,
+    },
+}
+
+//Streams games one by one, so only the game currently being parsed is held in memory.
+//A broken game is reported as an error and skipped, the following games are still read.
+pub struct PgnReader<R: BufRead> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    pending_tag_line: Option<String>,
+}
+
+#[derive(Default)]
+struct GameParser {
+    game: PgnGame,
+    board: Option<ChessBoard>,
+    variation_depth: usize,
+    in_comment: bool,
+    has_content: bool,
+    finished: bool,
+    error: Option<PgnError>,
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            pending_tag_line: None,
+        }
+    }
+
+    pub fn read_game(&mut self) -> Option<std::result::Result<PgnGame, PgnError>> {
+        let mut parser = GameParser::default();
+
+        loop {
+            let line = match self.pending_tag_line.take() {
+                Some(line) => line,
+                None => {
+                    self.line.clear();
+                    match self.reader.read_line(&mut self.line) {
+                        Ok(0) => break,
+                        Ok(_) => {
+                            self.line_number += 1;
+                            std::mem::take(&mut self.line)
+                        }
+                        Err(error) => return Some(Err(PgnError::Io(error))),
+                    }
+                }
+            };
+
+            //A tag after movetext means the previous game ended without a result token
+            if parser.is_movetext_started()
+                && !parser.in_comment
+                && line.trim_start().starts_with('[')
+            {
+                self.pending_tag_line = Some(line);
+                break;
+            }
+
+            parser.parse_line(&line, self.line_number);
+            if parser.finished {
+                break;
+            }
+        }
+
+        if !parser.has_content {
+            return None;
+        }
+
+        if parser.in_comment && parser.error.is_none() {
+            parser.error = Some(PgnError::UnterminatedComment {
+                line: self.line_number,
+            });
+        }
+
+        Some(parser.finish(self.line_number))
+    }
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = std::result::Result<PgnGame, PgnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_game()
+    }
+}
+
+impl GameParser {
+    fn is_movetext_started(&self) -> bool {
+        self.board.is_some()
+    }
+
+    fn parse_line(&mut self, line: &str, line_number: usize) {
+        let mut rest = line.trim_end_matches(['\n', '\r']);
+
+        if !self.in_comment {
+            if rest.starts_with('%') {
+                return;
+            }
+
+            if !self.is_movetext_started() && rest.trim_start().starts_with('[') {
+                self.parse_tags(rest.trim(), line_number);
+                return;
+            }
+        }
+
+        while !rest.is_empty() && !self.finished {
+            if self.in_comment {
+                match rest.find('}') {
+                    Some(index) => {
+                        self.in_comment = false;
+                        rest = &rest[index + 1..];
+                        continue;
+                    }
+                    None => return,
+                }
+            }
+
+            rest = rest.trim_start();
+            let Some(character) = rest.chars().next() else {
+                return;
+            };
+
+            self.has_content = true;
+            match character {
+                '{' => {
+                    self.in_comment = true;
+                    rest = &rest[1..];
+                }
+                ';' => return,
+                '(' => {
+                    self.variation_depth += 1;
+                    rest = &rest[1..];
+                }
+                ')' => {
+                    if self.variation_depth == 0 {
+                        self.set_error(PgnError::UnbalancedVariation { line: line_number });
+                    } else {
+                        self.variation_depth -= 1;
+                    }
+                    rest = &rest[1..];
+                }
+                _ => {
+                    let token_end = rest
+                        .find(|c: char| c.is_whitespace() || "{}();".contains(c))
+                        .unwrap_or(rest.len());
+                    self.parse_token(&rest[..token_end], line_number);
+                    rest = &rest[token_end..];
+                }
+            }
+        }
+    }
+
+    fn parse_tags(&mut self, mut rest: &str, line_number: usize) {
+        self.has_content = true;
+        while let Some(tag) = rest.strip_prefix('[') {
+            let Some((name, value_part)) = tag.split_once(char::is_whitespace) else {
+                return self.set_error(PgnError::InvalidTag {
+                    line: line_number,
+                    text: rest.to_string(),
+                });
+            };
+
+            let value_part = value_part.trim_start();
+            let mut value = String::new();
+            let mut escaped = false;
+            let mut value_end = None;
+            for (index, character) in value_part.char_indices().skip(1) {
+                match character {
+                    _ if escaped => {
+                        value.push(character);
+                        escaped = false;
+                    }
+                    '\\' => escaped = true,
+                    '"' => {
+                        value_end = Some(index);
+                        break;
+                    }
+                    _ => value.push(character),
+                }
+            }
+
+            let closing =
+                value_end.and_then(|index| value_part[index + 1..].trim_start().strip_prefix(']'));
+            match closing {
+                Some(remaining) if value_part.starts_with('"') => {
+                    self.game.headers.push((name.to_string(), value));
+                    rest = remaining.trim_start();
+                }
+                _ => {
+                    return self.set_error(PgnError::InvalidTag {
+                        line: line_number,
+                        text: rest.to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    fn parse_token(&mut self, token: &str, line_number: usize) {
+        if let Some(result) = GameResult::from_token(token) {
+            if self.variation_depth == 0 {
+                self.start_movetext(line_number);
+                self.game.result = result;
+                self.finished = true;
+            }
+            return;
+        }
+
+        //NAGs and move numbers like 12. or 12... possibly glued to the move itself
+        if token.starts_with('$') {
+            return;
+        }
+        let number_end = token
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(token.len());
+        let token = if number_end == token.len() {
+            ""
+        } else if token[number_end..].starts_with('.') {
+            token[number_end..].trim_start_matches('.')
+        } else {
+            token
+        };
+        if token.is_empty() || self.variation_depth > 0 {
+            return;
+        }
+
+        self.start_movetext(line_number);
+        if self.error.is_some() {
+            return;
+        }
+
+        let Some(board) = self.board.as_mut() else {
+            return;
+        };
+        match board.parse_san(token) {
+            Ok(mv) => {
+                board.play(mv);
+                self.game.moves.push(mv);
+            }
+            Err(error) => {
+                let side = board.side_to_move();
+                let move_number = board.full_move_counter();
+                self.set_error(PgnError::IllegalMove {
+                    line: line_number,
+                    move_number,
+                    side,
+                    san: token.to_string(),
+                    error,
+                });
+            }
+        }
+    }
+
+    fn start_movetext(&mut self, line_number: usize) {
+        if self.board.is_some() {
+            return;
+        }
+
+        let start_position = match self.game.get_header("FEN") {
+            Some(fen) => match FEN::parse(fen).and_then(|fen| ChessBoard::try_from_fen(&fen)) {
+                Ok(board) => board,
+                Err(error) => {
+                    self.set_error(PgnError::InvalidFen {
+                        line: line_number,
+                        error,
+                    });
+                    ChessBoard::default()
+                }
+            },
+            None => ChessBoard::from_fen(&FEN::start_position()),
+        };
+
+        self.game.start_position = start_position;
+        self.board = Some(start_position);
+    }
+
+    fn set_error(&mut self, error: PgnError) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    fn finish(mut self, line_number: usize) -> std::result::Result<PgnGame, PgnError> {
+        self.start_movetext(line_number);
+        if self.error.is_none() && self.variation_depth > 0 {
+            self.error = Some(PgnError::UnbalancedVariation { line: line_number });
+        }
+
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.game),
+        }
+    }
+}
+
+impl Display for PgnError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            PgnError::Io(error) => write!(formatter, "failed to read PGN: {error}"),
+            PgnError::InvalidTag { line, text } => {
+                write!(formatter, "line {line}: invalid tag pair '{text}'")
+            }
+            PgnError::InvalidFen { line, error } => {
+                write!(formatter, "line {line}: invalid FEN tag: {error}")
+            }
+            PgnError::IllegalMove {
+                line,
+                move_number,
+                side,
+                san,
+                error,
+            } => {
+                let dots = if *side == Side::WHITE { "." } else { "..." };
+                write!(
+                    formatter,
+                    "line {line}: move {move_number}{dots} {san}: {error}"
+                )
+            }
+            PgnError::UnbalancedVariation { line } => {
+                write!(formatter, "line {line}: unbalanced variation parentheses")
+            }
+            PgnError::UnterminatedComment { line } => {
+                write!(formatter, "line {line}: unterminated comment")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
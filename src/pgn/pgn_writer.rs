@@ -0,0 +1,189 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{ChessBoard, FenError, Move, MoveParseError, Side, FEN};
+
+use super::GameResult;
+
+const LINE_LENGTH: usize = 79;
+const SEVEN_TAG_ROSTER: [(&str, &str); 6] = [
+    ("Event", "?"),
+    ("Site", "?"),
+    ("Date", "????.??.??"),
+    ("Round", "?"),
+    ("White", "?"),
+    ("Black", "?"),
+];
+
+//Collects a game move by move and renders it in PGN export format.
+//Every move is checked against the current position before it is accepted.
+#[derive(Clone)]
+pub struct PgnWriter {
+    headers: Vec<(String, String)>,
+    start_position: ChessBoard,
+    board: ChessBoard,
+    moves: Vec<WrittenMove>,
+    result: GameResult,
+}
+
+#[derive(Clone)]
+struct WrittenMove {
+    san: String,
+    move_number: u16,
+    side: Side,
+    comment: Option<String>,
+}
+
+impl PgnWriter {
+    pub fn new(start_position: &FEN) -> std::result::Result<Self, FenError> {
+        let board = ChessBoard::try_from_fen(start_position)?;
+        Ok(Self::from_board(&board))
+    }
+
+    pub fn from_board(start_position: &ChessBoard) -> Self {
+        Self {
+            headers: Vec::new(),
+            start_position: *start_position,
+            board: *start_position,
+            moves: Vec::new(),
+            result: GameResult::Unknown,
+        }
+    }
+
+    //Replaces the value if the tag already exists. Result, SetUp and FEN are derived from the game
+    pub fn set_header(&mut self, name: &str, value: &str) {
+        match self.headers.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, current)) => *current = value.to_string(),
+            None => self.headers.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn push_move(&mut self, mv: Move) -> std::result::Result<(), MoveParseError> {
+        self.push_move_internal(mv, None)
+    }
+
+    pub fn push_move_with_comment(
+        &mut self,
+        mv: Move,
+        comment: &str,
+    ) -> std::result::Result<(), MoveParseError> {
+        self.push_move_internal(mv, Some(comment))
+    }
+
+    pub fn push_moves(&mut self, moves: &[Move]) -> std::result::Result<(), MoveParseError> {
+        for &mv in moves {
+            self.push_move(mv)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn set_result(&mut self, result: GameResult) {
+        self.result = result;
+    }
+
+    #[inline]
+    pub fn current_position(&self) -> &ChessBoard {
+        &self.board
+    }
+
+    fn push_move_internal(
+        &mut self,
+        mv: Move,
+        comment: Option<&str>,
+    ) -> std::result::Result<(), MoveParseError> {
+        if !self.board.is_legal(mv) {
+            return Err(MoveParseError::IllegalMove(mv.to_string()));
+        }
+
+        //Braces cannot be escaped inside PGN comments
+        let comment = comment.map(|comment| comment.replace(['{', '}'], ""));
+        self.moves.push(WrittenMove {
+            san: self.board.move_to_san(mv),
+            move_number: self.board.full_move_counter(),
+            side: self.board.side_to_move(),
+            comment,
+        });
+        self.board.play(mv);
+        Ok(())
+    }
+
+    fn write_movetext(&self) -> String {
+        let mut tokens = Vec::new();
+        let mut needs_number = true;
+
+        for written_move in &self.moves {
+            let move_number = written_move.move_number;
+            if written_move.side == Side::WHITE {
+                tokens.push(format!("{move_number}."));
+            } else if needs_number {
+                tokens.push(format!("{move_number}..."));
+            }
+            tokens.push(written_move.san.clone());
+
+            needs_number = false;
+            if let Some(comment) = &written_move.comment {
+                tokens.push(format!("{{{comment}}}"));
+                needs_number = true;
+            }
+        }
+        tokens.push(self.result.to_string());
+
+        let mut result = String::new();
+        let mut line_length = 0;
+        for token in tokens {
+            if line_length > 0 && line_length + 1 + token.len() > LINE_LENGTH {
+                result.push('\n');
+                line_length = 0;
+            } else if line_length > 0 {
+                result.push(' ');
+                line_length += 1;
+            }
+
+            result.push_str(&token);
+            line_length += token.len();
+        }
+        result
+    }
+}
+
+impl Display for PgnWriter {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let header_value = |name: &str| {
+            self.headers
+                .iter()
+                .find(|(tag, _)| tag == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let mut headers = Vec::new();
+        for (name, default) in SEVEN_TAG_ROSTER {
+            headers.push((name, header_value(name).unwrap_or(default).to_string()));
+        }
+        headers.push(("Result", self.result.to_string()));
+
+        let start_fen = self.start_position.get_fen().to_string();
+        if self.start_position.is_chess_960() && header_value("Variant").is_none() {
+            headers.push(("Variant", String::from("Chess960")));
+        }
+        if start_fen != FEN::start_position().to_string() || self.start_position.is_chess_960() {
+            headers.push(("SetUp", String::from("1")));
+            headers.push(("FEN", start_fen));
+        }
+
+        for (name, value) in &self.headers {
+            let derived = ["Result", "SetUp", "FEN"].contains(&name.as_str());
+            if !derived && !SEVEN_TAG_ROSTER.iter().any(|(tag, _)| tag == name) {
+                headers.push((name.as_str(), value.clone()));
+            }
+        }
+
+        for (name, value) in headers {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(formatter, "[{name} \"{value}\"]")?;
+        }
+
+        writeln!(formatter)?;
+        writeln!(formatter, "{}", self.write_movetext())?;
+        writeln!(formatter)
+    }
+}
@@ -0,0 +1,6 @@
+mod polyglot_book;
+mod polyglot_book_builder;
+mod polyglot_key;
+
+pub use polyglot_book::{BookMove, PolyglotBook, PolyglotEntry};
+pub use polyglot_book_builder::PolyglotBookBuilder;
@@ -0,0 +1,196 @@
+use std::{
+    collections::hash_map::RandomState,
+    fs::File,
+    hash::BuildHasher,
+    io::{BufReader, Read, Result, Seek, SeekFrom},
+    path::Path,
+};
+
+use crate::{ChessBoard, Move};
+
+//One 16 byte record of a Polyglot book, stored big endian and sorted by key
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct PolyglotEntry {
+    pub(super) key: u64,
+    pub(super) raw_move: u16,
+    pub(super) weight: u16,
+    pub(super) learn: u32,
+}
+
+impl PolyglotEntry {
+    pub const SIZE: usize = 16;
+
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        Self {
+            key: u64::from_be_bytes(bytes[0..8].try_into().unwrap_or_default()),
+            raw_move: u16::from_be_bytes([bytes[8], bytes[9]]),
+            weight: u16::from_be_bytes([bytes[10], bytes[11]]),
+            learn: u32::from_be_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]),
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut result = [0; Self::SIZE];
+        result[0..8].copy_from_slice(&self.key.to_be_bytes());
+        result[8..10].copy_from_slice(&self.raw_move.to_be_bytes());
+        result[10..12].copy_from_slice(&self.weight.to_be_bytes());
+        result[12..16].copy_from_slice(&self.learn.to_be_bytes());
+        result
+    }
+
+    #[inline]
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    #[inline]
+    pub fn raw_move(&self) -> u16 {
+        self.raw_move
+    }
+
+    #[inline]
+    pub fn weight(&self) -> u16 {
+        self.weight
+    }
+
+    #[inline]
+    pub fn learn(&self) -> u32 {
+        self.learn
+    }
+}
+
+//Book entry decoded into a legal move of the position it was looked up for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BookMove {
+    mv: Move,
+    weight: u16,
+    learn: u32,
+}
+
+impl BookMove {
+    #[inline]
+    pub fn mv(&self) -> Move {
+        self.mv
+    }
+
+    #[inline]
+    pub fn weight(&self) -> u16 {
+        self.weight
+    }
+
+    #[inline]
+    pub fn learn(&self) -> u32 {
+        self.learn
+    }
+}
+
+//Looks positions up directly in the file, only the entries of the probed position are read
+pub struct PolyglotBook<R: Read + Seek> {
+    reader: R,
+    entry_count: u64,
+}
+
+impl PolyglotBook<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> PolyglotBook<R> {
+    pub fn new(mut reader: R) -> Result<Self> {
+        let length = reader.seek(SeekFrom::End(0))?;
+        Ok(Self {
+            reader,
+            entry_count: length / PolyglotEntry::SIZE as u64,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.entry_count
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entry_count == 0
+    }
+
+    pub fn get_entries(&mut self, key: u64) -> Result<Vec<PolyglotEntry>> {
+        let mut low = 0;
+        let mut high = self.entry_count;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.read_entry(middle)?.key < key {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        let mut result = Vec::new();
+        for index in low..self.entry_count {
+            let entry = self.read_entry(index)?;
+            if entry.key != key {
+                break;
+            }
+            result.push(entry);
+        }
+        Ok(result)
+    }
+
+    //Entries that do not decode into a legal move are skipped
+    pub fn get_moves(&mut self, board: &ChessBoard) -> Result<Vec<BookMove>> {
+        let entries = self.get_entries(board.polyglot_key())?;
+        Ok(entries
+            .iter()
+            .filter_map(|entry| {
+                board.move_from_polyglot(entry.raw_move).map(|mv| BookMove {
+                    mv,
+                    weight: entry.weight,
+                    learn: entry.learn,
+                })
+            })
+            .collect())
+    }
+
+    pub fn best_move(&mut self, board: &ChessBoard) -> Result<Option<Move>> {
+        Ok(self
+            .get_moves(board)?
+            .iter()
+            .filter(|book_move| book_move.weight > 0)
+            .max_by_key(|book_move| book_move.weight)
+            .map(|book_move| book_move.mv))
+    }
+
+    //Picks a move with probability proportional to its weight, random can be any value.
+    //Moves with zero weight are never played
+    pub fn weighted_move(&mut self, board: &ChessBoard, random: u64) -> Result<Option<Move>> {
+        let moves = self.get_moves(board)?;
+        let total_weight: u64 = moves.iter().map(|book_move| book_move.weight as u64).sum();
+        if total_weight == 0 {
+            return Ok(None);
+        }
+
+        let mut remaining = random % total_weight;
+        for book_move in moves {
+            if remaining < book_move.weight as u64 {
+                return Ok(Some(book_move.mv));
+            }
+            remaining -= book_move.weight as u64;
+        }
+        Ok(None)
+    }
+
+    pub fn random_move(&mut self, board: &ChessBoard) -> Result<Option<Move>> {
+        let random = RandomState::new().hash_one(board.polyglot_key());
+        self.weighted_move(board, random)
+    }
+
+    fn read_entry(&mut self, index: u64) -> Result<PolyglotEntry> {
+        let mut bytes = [0; PolyglotEntry::SIZE];
+        self.reader
+            .seek(SeekFrom::Start(index * PolyglotEntry::SIZE as u64))?;
+        self.reader.read_exact(&mut bytes)?;
+        Ok(PolyglotEntry::from_bytes(&bytes))
+    }
+}
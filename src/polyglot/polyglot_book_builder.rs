@@ -0,0 +1,145 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Result, Write},
+    path::Path,
+};
+
+use crate::{ChessBoard, GameResult, Move, MoveParseError, PgnGame, Side};
+
+use super::PolyglotEntry;
+
+//Collects moves played from every position of the added games and turns them into a Polyglot
+//book. Weight follows Polyglot's own make_book, two points per win and one per draw for the
+//side that played the move, unfinished games count as draws
+pub struct PolyglotBookBuilder {
+    max_ply: u16,
+    min_frequency: u32,
+    moves: HashMap<(u64, u16), MoveStats>,
+}
+
+#[derive(Clone, Copy, Default)]
+struct MoveStats {
+    games: u32,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+}
+
+impl MoveStats {
+    fn score(&self) -> u64 {
+        2 * self.wins as u64 + self.draws as u64
+    }
+
+    //Learn value packs wins in the high and losses in the low 16 bits
+    fn learn(&self) -> u32 {
+        self.wins.min(u16::MAX as u32) << 16 | self.losses.min(u16::MAX as u32)
+    }
+}
+
+impl PolyglotBookBuilder {
+    //Only the first max_ply moves of each game are added, and moves played fewer than
+    //min_frequency times are left out of the book
+    pub fn new(max_ply: u16, min_frequency: u32) -> Self {
+        Self {
+            max_ply,
+            min_frequency,
+            moves: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    #[inline]
+    pub fn add_pgn_game(&mut self, game: &PgnGame) -> std::result::Result<(), MoveParseError> {
+        self.add_game(game.start_position(), game.moves(), game.result())
+    }
+
+    //Games with an illegal move are rejected as a whole
+    pub fn add_game(
+        &mut self,
+        start_position: &ChessBoard,
+        moves: &[Move],
+        result: GameResult,
+    ) -> std::result::Result<(), MoveParseError> {
+        let mut board = *start_position;
+        let mut played = Vec::with_capacity(moves.len().min(self.max_ply as usize));
+        for &mv in moves.iter().take(self.max_ply as usize) {
+            if !board.is_legal(mv) {
+                return Err(MoveParseError::IllegalMove(mv.to_string()));
+            }
+
+            played.push((
+                board.polyglot_key(),
+                board.move_to_polyglot(mv),
+                board.side_to_move(),
+            ));
+            board.play(mv);
+        }
+
+        for (key, raw_move, side) in played {
+            let stats = self.moves.entry((key, raw_move)).or_default();
+            stats.games += 1;
+            match (result, side) {
+                (GameResult::WhiteWins, Side::WHITE) | (GameResult::BlackWins, Side::BLACK) => {
+                    stats.wins += 1
+                }
+                (GameResult::WhiteWins, _) | (GameResult::BlackWins, _) => stats.losses += 1,
+                _ => stats.draws += 1,
+            }
+        }
+
+        Ok(())
+    }
+
+    //Entries sorted by key and then by weight, weights are scaled down when the best move
+    //would not fit into 16 bits
+    pub fn entries(&self) -> Vec<PolyglotEntry> {
+        let max_score = self
+            .moves
+            .values()
+            .map(MoveStats::score)
+            .max()
+            .unwrap_or_default();
+        let scale = max_score.div_ceil(u16::MAX as u64).max(1);
+
+        let mut result: Vec<PolyglotEntry> = self
+            .moves
+            .iter()
+            .filter(|(_, stats)| stats.games >= self.min_frequency)
+            .map(|(&(key, raw_move), stats)| PolyglotEntry {
+                key,
+                raw_move,
+                weight: (stats.score() / scale) as u16,
+                learn: stats.learn(),
+            })
+            .collect();
+
+        result.sort_by(|a, b| {
+            a.key
+                .cmp(&b.key)
+                .then(b.weight.cmp(&a.weight))
+                .then(a.raw_move.cmp(&b.raw_move))
+        });
+        result
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        for entry in self.entries() {
+            writer.write_all(&entry.to_bytes())?;
+        }
+        writer.flush()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.write(&mut BufWriter::new(File::create(path)?))
+    }
+}